@@ -0,0 +1,123 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+use crate::{Error, Result, Source};
+
+/// Default location of the answer store, next to `input/`.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// No answer has been confirmed for this part yet.
+    Unconfirmed,
+    Correct,
+    Regression { expected: String },
+}
+
+/// Confirmed answers for the real puzzle inputs, keyed by day and part:
+/// ```toml
+/// [day07]
+/// part1 = "95437"
+/// ```
+#[derive(Debug)]
+pub struct Answers {
+    path: PathBuf,
+    table: Table,
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+fn part_key(part: u8) -> String {
+    format!("part{}", part)
+}
+
+impl Answers {
+    /// Reads the answer store, a missing file is treated as an empty store.
+    pub fn load(path: impl AsRef<Path>) -> Result<Answers> {
+        let path = path.as_ref().to_path_buf();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(source) => return Err(Error::Io { path, source }),
+        };
+        let table = text.parse::<Table>().map_err(|e| {
+            let at = e.span().map_or(&text[..0], |span| &text[span.start..]);
+            Source::new(&text).error(at, e.message()).in_file(&path)
+        })?;
+        Ok(Answers { path, table })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.table.get(&day_key(day))?
+            .get(part_key(part))?
+            .as_str()
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: &str) {
+        let entry = self.table.entry(day_key(day))
+            .or_insert_with(|| Value::Table(Table::new()));
+        if let Value::Table(parts) = entry {
+            parts.insert(part_key(part), Value::String(answer.to_string()));
+        } else {
+            *entry = Value::Table(Table::from_iter([(part_key(part), Value::String(answer.to_string()))]));
+        }
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unconfirmed,
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Regression { expected: expected.to_string() },
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(&self.path, self.table.to_string())
+            .map_err(|source| Error::Io { path: self.path.clone(), source })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::load("does/not/exist.toml").unwrap();
+        assert_eq!(answers.check(7, 1, "95437"), Verdict::Unconfirmed);
+        answers.set(7, 1, "95437");
+        assert_eq!(answers.check(7, 1, "95437"), Verdict::Correct);
+        assert_eq!(answers.check(7, 1, "95438"), Verdict::Regression { expected: "95437".to_string() });
+        assert_eq!(answers.check(7, 2, "95437"), Verdict::Unconfirmed);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        let mut answers = Answers::load(&path).unwrap();
+        answers.set(5, 1, "CMZ");
+        answers.set(10, 2, "##..\n..##\n");
+        answers.save().unwrap();
+        let loaded = Answers::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.get(5, 1), Some("CMZ"));
+        assert_eq!(loaded.get(10, 2), Some("##..\n..##\n"));
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let path = env::temp_dir().join(format!("aoc-answers-invalid-{}.toml", std::process::id()));
+        fs::write(&path, "[day01]\npart1 = 12\npart2 = \"3\n").unwrap();
+        let error = Answers::load(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(error, Error::Parse { line: 3, .. }), "{:?}", error);
+    }
+}
@@ -0,0 +1,120 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::{Day, load_input, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "At least one sample is required");
+        samples.sort();
+        let middle = samples.len() / 2;
+        let median = if samples.len() % 2 == 1 {
+            samples[middle]
+        } else {
+            (samples[middle - 1] + samples[middle]) / 2
+        };
+        Stats { min: samples[0], median, max: samples[samples.len() - 1] }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "min_ns": self.min.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "max_ns": self.max.as_nanos() as u64,
+        })
+    }
+}
+
+/// Timings of the separate phases of one day.
+pub struct DayBench {
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayBench {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "day": self.day,
+            "runs": self.runs,
+            "parse": self.parse.to_json(),
+            "part1": self.part1.to_json(),
+            "part2": self.part2.to_json(),
+        })
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (Duration, T) {
+    let start = Instant::now();
+    let result = black_box(f());
+    (start.elapsed(), result)
+}
+
+/// Runs parsing and both parts `runs` times each, reading the input file only once.
+pub fn bench_day(day: &Day, path: &str, runs: usize) -> Result<DayBench> {
+    assert!(runs > 0, "At least one run is required");
+    let data = load_input(path)?;
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let input = data.clone();
+        let (duration, parsed) = time(|| day.parse(input));
+        let parsed = parsed.map_err(|e| e.in_file(path))?;
+        parse.push(duration);
+        let (duration, answer) = time(|| day.part1(&parsed));
+        answer.map_err(|e| e.in_file(path))?;
+        part1.push(duration);
+        let (duration, answer) = time(|| day.part2(&parsed));
+        answer.map_err(|e| e.in_file(path))?;
+        part2.push(duration);
+    }
+    Ok(DayBench {
+        day: day.number,
+        runs,
+        parse: Stats::from_samples(parse),
+        part1: Stats::from_samples(part1),
+        part2: Stats::from_samples(part2),
+    })
+}
+
+pub fn print_bench(bench: &DayBench) {
+    println!("Day {:02} ({} runs)", bench.day, bench.runs);
+    for (phase, stats) in [("parse", &bench.parse), ("part1", &bench.part1), ("part2", &bench.part2)] {
+        println!("  {:<6} min {:>10.3?}   median {:>10.3?}   max {:>10.3?}", phase, stats.min, stats.median, stats.max);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::days;
+
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(Stats::from_samples(vec![ms(3), ms(1), ms(2)]), Stats { min: ms(1), median: ms(2), max: ms(3) });
+        assert_eq!(Stats::from_samples(vec![ms(4), ms(1), ms(2), ms(8)]).median, ms(3));
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let bench = bench_day(days::get(1).unwrap(), "input/01.test.txt", 3).unwrap();
+        assert_eq!((bench.day, bench.runs), (1, 3));
+        assert!(bench.parse.min <= bench.parse.median && bench.parse.median <= bench.parse.max);
+        assert_eq!(bench.to_json()["part2"].as_object().unwrap().len(), 3);
+    }
+}
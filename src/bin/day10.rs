@@ -1,81 +1,99 @@
-use std::cmp::max;
+use std::path::Path;
+use std::{env, fs, process};
 
-use adventofcode2022::load_input;
+use adventofcode2022::days::day10::{Cpu, Crt, InstructionSet, Program, Tick, to_pbm, to_pgm, to_text};
+use adventofcode2022::days::day10::Day10;
+use adventofcode2022::{Error, load_input, Params, Result, solve, Solution};
 
-fn prepare_input(s: String) -> Vec<i32> {
-    s.lines()
-        .map(|x| match x {
-            "noop" => vec![0],
-            x => { vec![0, x.split_once(" ").unwrap().1.parse().unwrap()] }
+// programs for the extended instruction set, which includes the puzzle's noop and addx
+fn load_program(path: Option<&String>) -> Program {
+    let path = path.map_or("input/10.txt", String::as_str);
+    load_input(path)
+        .and_then(|text| InstructionSet::extended().assemble(&text).map_err(|e| e.in_file(path)))
+        .unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
         })
-        .flatten()
-        .collect()
 }
 
-fn part1(instructions: &Vec<i32>) -> i32 {
-    let mut signal_strength: i32 = 0;
-    let mut x_register: i32 = 1;
-    for i in [20, 60, 100, 140, 180, 220] {
-        x_register = x_register + instructions[max(0, i - 40 - 1) as usize..i as usize - 1].iter().sum::<i32>();
-        signal_strength += i * x_register;
+// [<program>] [--width <n>] [--height <n>] [--sprite <n>]
+fn load_screen(args: &[String]) -> (Program, Crt) {
+    let (path, options) = match args.first() {
+        Some(path) if !path.starts_with("--") => (Some(path), &args[1..]),
+        _ => (None, args),
+    };
+    let mut params = Params::new(Day10::PARAMS);
+    for option in options.chunks(2) {
+        let result = match option {
+            [name, value] if name.starts_with("--") => params.set(&name[2..], value),
+            _ => Err(Error::invalid(format!("Expected --<param> <value> instead of '{}'", option.join(" ")))),
+        };
+        if let Err(e) = result {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
     }
-    signal_strength
+    (load_program(path), Crt::from_params(&params))
 }
 
-
-fn part2(instructions: &Vec<i32>) -> String {
-    let mut pixels = String::new();
-    let mut x_register: i32 = 1;
-    for position in 0..240 {
-        let location = position % 40;
-        if x_register.abs_diff(location) <= 1 { pixels.push('#') } else { pixels.push('.') }
-        if location == 39 { pixels.push('\n') }
-        x_register += instructions[position as usize];
+// one PGM image per cycle, e.g. for `convert -scale 1000% frames/*.pgm beam.gif`
+fn write_frames(dir: &Path, program: &Program, crt: &Crt) -> Result<()> {
+    fs::create_dir_all(dir).map_err(|source| Error::Io { path: dir.to_path_buf(), source })?;
+    for frame in crt.frames(program) {
+        let path = dir.join(format!("frame{:05}.pgm", frame.cycle));
+        fs::write(&path, frame.to_pgm()).map_err(|source| Error::Io { path, source })?;
     }
-    pixels
+    Ok(())
 }
 
 fn main() {
-    let data = load_input("input/10.txt");
-    let input = prepare_input(data);
-    println!("First task: {:?}", part1(&input));
-    println!("Second task: \n{}", part2(&input));
-}
-
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        let data = load_input("input/10.test.txt");
-        let input = prepare_input(data);
-        assert_eq!(part1(&input), 13140);
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        // day10 --trace [<program>] prints the registers during every cycle
+        Some("--trace") => {
+            let program = load_program(args.get(1));
+            let names: Vec<_> = program.isa.registers().collect();
+            Cpu::new(&program).run(&mut |tick: Tick| {
+                let registers: Vec<_> = names.iter().zip(tick.registers)
+                    .map(|(name, value)| format!("{} = {}", name, value))
+                    .collect();
+                println!("during cycle {}: {}", tick.cycle, registers.join(", "));
+            });
+        }
+        // day10 --screen [<program>] [--width <n>] [--height <n>] [--sprite <n>] shows the CRT image
+        // part 2 reads the letters from, --pbm and --pgm export it and --animate prints it cycle by cycle
+        Some("--screen") => {
+            let (program, crt) = load_screen(&args[1..]);
+            print!("{}", to_text(&crt.draw(&program)));
+        }
+        Some("--pbm") => {
+            let (program, crt) = load_screen(&args[1..]);
+            print!("{}", to_pbm(&crt.draw(&program)));
+        }
+        Some("--pgm") => {
+            let (program, crt) = load_screen(&args[1..]);
+            print!("{}", to_pgm(&crt.draw(&program)));
+        }
+        Some("--animate") => {
+            let (program, crt) = load_screen(&args[1..]);
+            for frame in crt.frames(&program) {
+                println!("{}", frame.to_text());
+            }
+        }
+        // day10 --frames <directory> [<program>] [--width <n>] ... writes every cycle as a PGM image
+        Some("--frames") => {
+            let Some(dir) = args.get(1) else {
+                eprintln!("Usage: day10 --frames <directory> [<program>] [--width <n>] [--height <n>] [--sprite <n>]");
+                process::exit(2);
+            };
+            let (program, crt) = load_screen(&args[2..]);
+            if let Err(e) = write_frames(Path::new(dir), &program, &crt) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+        // day10 --disassemble [<program>] prints the program without comments and blank lines
+        Some("--disassemble") => print!("{}", load_program(args.get(1))),
+        _ => solve::<Day10>("input/10.txt"),
     }
-
-    #[test]
-    fn test_part1_small() {
-        let data = load_input("input/10_small.test.txt");
-        let cycles = prepare_input(data);
-        assert_eq!(1 + cycles[..1].iter().sum::<i32>(), 1); // after cycle 1
-        assert_eq!(1 + cycles[..2].iter().sum::<i32>(), 1); // after cycle 2
-        assert_eq!(1 + cycles[..3].iter().sum::<i32>(), 4); // after cycle 3
-        assert_eq!(1 + cycles[..4].iter().sum::<i32>(), 4); // after cycle 4
-        assert_eq!(1 + cycles[..5].iter().sum::<i32>(), -1); // after cycle 5
-    }
-
-
-    #[test]
-    fn test_part2() {
-        let data = load_input("input/10.test.txt");
-        let input = prepare_input(data);
-        assert_eq!(part2(&input),
-                   "##..##..##..##..##..##..##..##..##..##..
-###...###...###...###...###...###...###.
-####....####....####....####....####....
-#####.....#####.....#####.....#####.....
-######......######......######......####
-#######.......#######.......#######.....\n".to_string());
-    }
-}
\ No newline at end of file
+}
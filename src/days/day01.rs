@@ -0,0 +1,62 @@
+use crate::{Error, Result, Solution, Source};
+
+fn prepare_input(s: String) -> Result<Vec<i32>> {
+    let src = Source::new(&s);
+    let mut elfs: Vec<i32> = s.split("\n\n")
+        .map(|elf| elf.lines().map(|l| src.parse::<i32>(l)).sum())
+        .collect::<Result<_>>()?;
+    if elfs.len() < 3 { return Err(Error::invalid("Expected at least three elves")); }
+
+    elfs.sort_by(|a, b| b.cmp(a));
+    Ok(elfs)
+}
+
+fn part1(input: &[i32]) -> i32 {
+    input[0]
+}
+
+fn part2(input: &[i32]) -> i32 {
+    input[0..3].iter().sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/01.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part1(&input), 24000);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/01.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part2(&input), 45000);
+    }
+}
\ No newline at end of file
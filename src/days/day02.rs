@@ -0,0 +1,97 @@
+use crate::{Result, Solution, Source};
+
+// resulting points: (outcome) + (value of the shape we selected)
+#[allow(clippy::identity_op)]
+fn game2points_part1(game: &str) -> i32 {
+    match game {
+        "A X" => 3 + 1,
+        "A Y" => 6 + 2,
+        "A Z" => 0 + 3,
+        "B X" => 0 + 1,
+        "B Y" => 3 + 2,
+        "B Z" => 6 + 3,
+        "C X" => 6 + 1,
+        "C Y" => 0 + 2,
+        "C Z" => 3 + 3,
+        _ => unreachable!("Invalid input: {}", game)
+    }
+}
+
+// resulting points: (outcome) + (value of the shape we selected)
+#[allow(clippy::identity_op)]
+fn game2points_part2(game: &str) -> i32 {
+    match game {
+        "A X" => 0 + 3,
+        "A Y" => 3 + 1,
+        "A Z" => 6 + 2,
+        "B X" => 0 + 1,
+        "B Y" => 3 + 2,
+        "B Z" => 6 + 3,
+        "C X" => 0 + 2,
+        "C Y" => 3 + 3,
+        "C Z" => 6 + 1,
+        _ => unreachable!("Invalid input: {}", game)
+    }
+}
+
+fn prepare_input(s: String) -> Result<String> {
+    let src = Source::new(&s);
+    for line in s.lines() {
+        if !matches!(line.as_bytes(), [b'A'..=b'C', b' ', b'X'..=b'Z']) {
+            return Err(src.error(line, format!("Invalid game '{}'", line)));
+        }
+    }
+    Ok(s)
+}
+
+fn part1(input: &str) -> i32 {
+    input.lines()
+        .map(game2points_part1)
+        .sum()
+}
+
+fn part2(input: &str) -> i32 {
+    input.lines()
+        .map(game2points_part2)
+        .sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = String;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/02.test.txt").unwrap();
+        assert_eq!(part1(&data), 15);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/02.test.txt").unwrap();
+        assert_eq!(part2(&data), 12);
+    }
+}
\ No newline at end of file
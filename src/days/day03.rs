@@ -0,0 +1,111 @@
+use array_tool::vec::Intersect;
+use itertools::Itertools;
+use crate::{Result, Solution, Source};
+
+fn prepare_input(s: String) -> Result<Vec<Vec<char>>> {
+    let src = Source::new(&s);
+    let lines: Vec<&str> = s.lines().collect();
+    for line in &lines {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(src.error(&line[i..], format!("Invalid item '{}'", c)));
+        }
+        if line.is_empty() || !line.len().is_multiple_of(2) {
+            return Err(src.error(line, "Expected two compartments of equal size"));
+        }
+        let (first, second) = line.split_at(line.len() / 2);
+        if !first.chars().any(|c| second.contains(c)) {
+            return Err(src.error(line, "Compartments have no item in common"));
+        }
+    }
+    if !lines.len().is_multiple_of(3) {
+        return Err(src.error(&s[s.len()..], "Expected groups of three rucksacks"));
+    }
+    for group in lines.chunks(3) {
+        if !group[0].chars().any(|c| group[1].contains(c) && group[2].contains(c)) {
+            return Err(src.error(group[0], "Group has no badge in common"));
+        }
+    }
+    Ok(lines.iter()
+        .map(|l| l.chars().collect())
+        .collect())
+}
+
+fn char2prio(c: &char) -> i32 {
+    if c.is_lowercase() { *c as i32 - 96 } else { *c as i32 - 38 }
+}
+
+fn get_line_prio(line: &[char]) -> i32 {
+    let packsize = line.len() / 2;
+    let mut unique: char = ' ';
+    for c in &line[packsize..] {
+        if line[0..packsize].to_vec().iter().any(|ch| ch == c) {
+            unique = *c;
+            break; // we know that there is exactly one unique item
+        }
+    }
+    char2prio(&unique)
+}
+
+fn get_group_prio(group: &[Vec<char>]) -> i32 {
+    let (p1, p2, p3): (Vec<char>, Vec<char>, Vec<char>) = group.
+        iter()
+        .map(|f| f.to_owned())
+        .collect_tuple()
+        .unwrap();
+    let badge = p1.intersect(p2).intersect(p3)[0];
+    char2prio(&badge)
+}
+
+fn part1(input: &[Vec<char>]) -> i32 {
+    input.iter()
+        .map(|line| get_line_prio(line))
+        .sum()
+}
+
+fn part2(input: &[Vec<char>]) -> i32 {
+    input.chunks(3)
+        .map(get_group_prio)
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/03.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part1(&input), 157);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/03.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part2(&input), 70);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,97 @@
+use crate::{Result, Solution, Source};
+
+#[derive(Debug)]
+struct Elf {
+    lower: i32,
+    upper: i32,
+}
+
+#[derive(Debug)]
+pub struct PairOfElfs(Elf, Elf);
+
+impl PairOfElfs {
+    fn isfullycontained(&self) -> bool {
+        self.0.lower <= self.1.lower && self.0.upper >= self.1.upper ||
+            self.1.lower <= self.0.lower && self.1.upper >= self.0.upper
+    }
+
+    fn overlap(&self) -> bool {
+        !(self.0.upper < self.1.lower ||
+            self.1.upper < self.0.lower)
+    }
+}
+
+impl PairOfElfs {
+    fn parse(src: &Source, s: &str) -> Result<PairOfElfs> {
+        let (elf0, elf1) = src.split_once(s, ",")?;
+        Ok(PairOfElfs(Elf::parse(src, elf0)?, Elf::parse(src, elf1)?))
+    }
+}
+
+impl Elf {
+    fn parse(src: &Source, s: &str) -> Result<Elf> {
+        let (lower, upper) = src.split_once(s, "-")?;
+        let elf = Elf { lower: src.parse(lower)?, upper: src.parse(upper)? };
+        if elf.lower > elf.upper {
+            return Err(src.error(s, "Section range ends before it starts"));
+        }
+        Ok(elf)
+    }
+}
+
+fn prepare_input(s: String) -> Result<Vec<PairOfElfs>> {
+    let src = Source::new(&s);
+    s.lines()
+        .map(|l| PairOfElfs::parse(&src, l))
+        .collect()
+}
+
+fn part1(input: &[PairOfElfs]) -> i32 {
+    input.iter().filter(|p| p.isfullycontained()).count() as i32
+}
+
+fn part2(input: &[PairOfElfs]) -> i32 {
+    input.iter().filter(|p| p.overlap()).count() as i32
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<PairOfElfs>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/04.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part1(&input), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/04.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part2(&input), 4);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use crate::{Error, Result, Solution, Source};
+
+type Stacks = HashMap<usize, Vec<char>>;
+
+#[derive(Debug)]
+pub struct Instruction {
+    from: usize,
+    to: usize,
+    amount: usize,
+}
+
+impl Instruction {
+    // move 1 from 2 to 1
+    fn parse(src: &Source, s: &str) -> Result<Instruction> {
+        let (amount, rest) = src.split_once(src.strip_prefix(s, "move ")?, " from ")?;
+        let (from, to) = src.split_once(rest, " to ")?;
+        Ok(Instruction {
+            from: src.parse(from)?,
+            to: src.parse(to)?,
+            amount: src.parse(amount)?,
+        })
+    }
+}
+
+fn prepare_input(s: String) -> Result<(Stacks, Vec<Instruction>)> {
+    let src = Source::new(&s);
+    let (input_stacks, input_instructions) = s.split_once("\n\n")
+        .ok_or_else(|| Error::invalid("Expected stacks and instructions separated by an empty line"))?;
+    // trim characters
+    let stacks = input_stacks.lines()
+        .map(|line| line.chars()
+            .enumerate()
+            .filter(|&(i, _)| i % 4 == 1)
+            .map(|(_, v)| v)
+            .collect::<Vec<char>>())
+        .collect::<Vec<Vec<char>>>();
+
+    // transpose, shorter lines are padded with air
+    let width = stacks.iter().map(|row| row.len()).max().unwrap_or(0);
+    let mut stacks_transposed = vec![Vec::new(); width];
+    for row in &stacks {
+        for (j, stack) in stacks_transposed.iter_mut().enumerate() {
+            stack.push(*row.get(j).unwrap_or(&' '));
+        }
+    }
+
+    // build hashmap while trimming whitespace ("air above the containers")
+    let labels = input_stacks.lines().last().unwrap_or_default();
+    let mut stacks_map: Stacks = HashMap::new();
+    for (j, stack) in stacks_transposed.into_iter().enumerate() {
+        let stack: Vec<char> = stack.into_iter()
+            .filter(|c| *c != ' ')
+            .rev()
+            .collect();
+        let label = labels.get(4 * j + 1..).unwrap_or_default();
+        let number = stack.first()
+            .and_then(|c| c.to_digit(10))
+            .ok_or_else(|| src.error(label, "Expected a stack number (less than 10 stacks)"))?;
+        stacks_map.insert(number as usize, stack[1..].to_vec());
+    }
+    if (1..=stacks_map.len()).any(|i| !stacks_map.contains_key(&i)) {
+        return Err(src.error(labels, "Stacks not consecutively numbered"));
+    }
+
+    // parse instructions, replaying the heights of the stacks to reject moves of crates that are not there
+    let mut heights: HashMap<usize, usize> = stacks_map.iter()
+        .map(|(number, stack)| (*number, stack.len()))
+        .collect();
+    let instructions: Vec<Instruction> = input_instructions.lines()
+        .map(|line| {
+            let instruction = Instruction::parse(&src, line)?;
+            for stack in [instruction.from, instruction.to] {
+                if !stacks_map.contains_key(&stack) {
+                    return Err(src.error(line, format!("Stack {} does not exist", stack)));
+                }
+            }
+            let height = heights[&instruction.from];
+            if height < instruction.amount {
+                return Err(src.error(line, format!("Stack {} only holds {} crates", instruction.from, height)));
+            }
+            *heights.get_mut(&instruction.from).unwrap() -= instruction.amount;
+            *heights.get_mut(&instruction.to).unwrap() += instruction.amount;
+            Ok(instruction)
+        })
+        .collect::<Result<_>>()?;
+    if let Some(number) = (1..=heights.len()).find(|number| heights[number] == 0) {
+        return Err(Error::invalid(format!("Stack {} is empty after the last move", number)));
+    }
+    Ok((stacks_map, instructions))
+}
+
+fn top_elements(stacks: &Stacks) -> String {
+    let mut part1solution = String::with_capacity(stacks.len());
+    for i in 1..stacks.len() + 1 {
+        part1solution.push(*stacks
+            .get(&i)
+            .expect("Stacks not consecutively numbered")
+            .last().expect("Empty stack"));
+    }
+    part1solution
+}
+
+fn solver(mut stacks: Stacks, instructions: &Vec<Instruction>, part1: bool) -> String {
+    for instruction in instructions {
+        let entry = stacks.get_mut(&instruction.from).expect("From-Stack does not exist");
+        let mut items = entry.split_off(entry.len() - instruction.amount);
+        if part1 { items.reverse(); }
+        stacks.get_mut(&instruction.to)
+            .expect("To-Stack does not exist")
+            .append(&mut items);
+    }
+    top_elements(&stacks)
+}
+
+
+fn part1(stacks: Stacks, instructions: &Vec<Instruction>) -> String {
+    solver(stacks, instructions, true)
+}
+
+fn part2(stacks: Stacks, instructions: &Vec<Instruction>) -> String {
+    solver(stacks, instructions, false)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Stacks, Vec<Instruction>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input.0.clone(), &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input.0.clone(), &input.1)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/05.test.txt").unwrap();
+        let (stacks, instructions) = prepare_input(data).unwrap();
+        assert_eq!(part1(stacks, &instructions), "CMZ".to_string());
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/05.test.txt").unwrap();
+        let (stacks, instructions) = prepare_input(data).unwrap();
+        assert_eq!(part2(stacks, &instructions), "MCD".to_string());
+    }
+
+    #[test]
+    fn test_unknown_stack() {
+        let data = load_input("input/05.test.txt").unwrap().replace("move 1 from 1 to 2", "move 1 from 1 to 4");
+        match prepare_input(data).unwrap_err() {
+            Error::Parse { line, message, .. } => {
+                assert_eq!(line, 9);
+                assert_eq!(message, "Stack 4 does not exist");
+            }
+            e => panic!("Unexpected error {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_too_many_crates() {
+        let data = load_input("input/05.test.txt").unwrap();
+        let error = prepare_input(data.replace("move 1 from 2 to 1", "move 5 from 2 to 1")).unwrap_err();
+        assert!(error.to_string().starts_with("line 6, column 1: Stack 2 only holds 3 crates"));
+        let error = prepare_input(data.replace("move 1 from 1 to 2", "move 2 from 1 to 2")).unwrap_err();
+        assert_eq!(error.to_string(), "Stack 1 is empty after the last move");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+use std::collections::VecDeque;
+
+use crate::{Error, Result, Solution};
+
+fn prepare_input(s: String) -> Result<Vec<char>> {
+    Ok(s.chars().collect())
+}
+
+fn ndifferent(buffer: &VecDeque<char>, marker_len: usize) -> bool {
+    if buffer.len() < marker_len { return false; }
+    for i in 0..marker_len {
+        for j in 0..marker_len {
+            if buffer[i] == buffer[j] && i != j { return false; }
+        }
+    }
+    true
+}
+
+fn solver(message: &[char], marker_len: usize) -> Result<usize> {
+    let mut buffer = VecDeque::with_capacity(marker_len + 1);
+    for (i, c) in message.iter().enumerate() {
+        buffer.push_back(*c);
+        if buffer.len() > marker_len { buffer.pop_front(); }
+        if ndifferent(&buffer, marker_len) { return Ok(i + 1); }
+    }
+    Err(Error::invalid(format!("No marker of {} different characters", marker_len)))
+}
+
+fn part1(message: &[char]) -> Result<usize> {
+    solver(message, 4)
+}
+
+fn part2(message: &[char]) -> Result<usize> {
+    solver(message, 14)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<char>;
+    type Answer1 = Result<usize>;
+    type Answer2 = Result<usize>;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/06.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part1(&input).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_part1_additional() {
+        let datas = ["bvwbjplbgvbhsrlpgdmjqwftvncz",
+                         "nppdvjthqldpwncqszvftbrmjlhg",
+                         "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg",
+                         "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"];
+        let inputs: Vec<Vec<char>> = datas.iter().map(|d| prepare_input(d.to_string()).unwrap()).collect();
+        assert_eq!(inputs.iter().map(|i| part1(i).unwrap()).collect::<Vec<usize>>(), vec![5, 6, 10, 11]);
+    }
+
+    #[test]
+    fn test_marker_at_the_end() {
+        assert_eq!(part1(&prepare_input("abcd".to_string()).unwrap()).unwrap(), 4);
+        assert_eq!(part1(&prepare_input("abab".to_string()).unwrap()).unwrap_err().to_string(),
+                   "No marker of 4 different characters");
+    }
+
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/06.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part2(&input).unwrap(), 19);
+    }
+
+    #[test]
+    fn test_part2_additional() {
+        let datas = ["bvwbjplbgvbhsrlpgdmjqwftvncz",
+                         "nppdvjthqldpwncqszvftbrmjlhg",
+                         "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg",
+                         "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"];
+        let inputs: Vec<Vec<char>> = datas.iter().map(|d| prepare_input(d.to_string()).unwrap()).collect();
+        assert_eq!(inputs.iter().map(|i| part2(i).unwrap()).collect::<Vec<usize>>(), vec![23, 23, 29, 26]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,103 @@
+pub use export::{from_json, human_size, to_json, to_ncdu, tree};
+pub use filesystem::{FileSystem, Kind, Node, NodeId};
+pub use query::DiskUsage;
+pub use shell::{Entry, Shell};
+pub use transcript::{Options, transcript, Traversal};
+
+use crate::{Error, Param, Params, Result, Solution};
+
+pub mod export;
+pub mod filesystem;
+pub mod query;
+pub mod shell;
+pub mod transcript;
+
+fn prepare_input(s: String) -> Result<FileSystem> {
+    shell::interpret(&s)
+}
+
+fn part1(input: &FileSystem, limit: u64) -> u64 {
+    DiskUsage::new(input).find(|size| size <= limit).map(|(_, size)| size).sum()
+}
+
+fn part2(input: &FileSystem, disk: u64, required: u64) -> Result<u64> {
+    let usage = DiskUsage::new(input);
+    let missing = usage.missing(disk, required);
+    usage.smallest_freeing(missing)
+        .map(|(_, size)| size)
+        .ok_or_else(|| Error::invalid(format!("Even deleting everything does not free {} bytes", missing)))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = FileSystem;
+    type Answer1 = u64;
+    type Answer2 = Result<u64>;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        Self::part1_with(input, &Params::new(Self::PARAMS))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        Self::part2_with(input, &Params::new(Self::PARAMS))
+    }
+
+    const PARAMS: &'static [Param] = &[
+        Param { name: "limit", default: 100000, min: 0, max: i64::MAX },
+        Param { name: "disk", default: 70000000, min: 0, max: i64::MAX },
+        Param { name: "required", default: 30000000, min: 0, max: i64::MAX },
+    ];
+
+    fn part1_with(input: &Self::Input, params: &Params) -> Self::Answer1 {
+        part1(input, params.get("limit") as u64)
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> Self::Answer2 {
+        part2(input, params.get("disk") as u64, params.get("required") as u64)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/07.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part1(&input, 100000), 95437);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/07.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part2(&input, 70000000, 30000000).unwrap(), 24933642);
+        assert_eq!(part2(&input, 70000000, 21700000).unwrap(), 94853);
+        assert_eq!(part2(&input, 70000000, 999999999).unwrap_err().to_string(),
+                   "Even deleting everything does not free 978381164 bytes");
+    }
+
+    #[test]
+    fn test_unknown_directory() {
+        let error = prepare_input("$ cd /\n$ ls\ndir a\n$ cd b\n".to_string()).unwrap_err();
+        assert_eq!(error.to_string(), "line 4, column 6: Unknown directory 'b'\n  $ cd b\n       ^");
+    }
+
+    #[test]
+    fn test_empty_directory() {
+        let fs = prepare_input("$ cd /\n$ ls\ndir a\n10 b\n$ cd a\n$ ls\n".to_string()).unwrap();
+        let a = fs.lookup("/a").unwrap();
+        assert!(fs.is_dir(a));
+        assert_eq!(fs.size(a), 0);
+        assert_eq!(part1(&fs, 100000), 10);
+    }
+}
\ No newline at end of file
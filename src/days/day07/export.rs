@@ -0,0 +1,196 @@
+use serde_json::{json, Value};
+
+use crate::{Error, Result};
+
+use super::{DiskUsage, FileSystem, Kind, NodeId};
+
+/// Size in bytes as `ls -h` shows it: powers of 1024, rounded up, one decimal below 10.
+pub fn human_size(bytes: u64) -> String {
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < 6 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => bytes.to_string(),
+        _ if value < 10.0 => format!("{:.1}{}", (value * 10.0).ceil() / 10.0, "KMGTPE".as_bytes()[unit - 1] as char),
+        _ => format!("{}{}", value.ceil(), "KMGTPE".as_bytes()[unit - 1] as char),
+    }
+}
+
+fn sorted_children(fs: &FileSystem, dir: NodeId) -> Vec<NodeId> {
+    let mut children = fs.children(dir).to_vec();
+    children.sort_by(|a, b| fs.name(*a).cmp(fs.name(*b)));
+    children
+}
+
+fn tree_lines(usage: &DiskUsage, dir: NodeId, prefix: &str, out: &mut String) {
+    let fs = usage.filesystem();
+    let children = sorted_children(fs, dir);
+    for (i, child) in children.iter().enumerate() {
+        let last = i + 1 == children.len();
+        let slash = if fs.is_dir(*child) { "/" } else { "" };
+        out.push_str(&format!("{}{}[{:>5}]  {}{}\n", prefix, if last { "└── " } else { "├── " },
+                              human_size(usage.size(*child)), fs.name(*child), slash));
+        if fs.is_dir(*child) {
+            tree_lines(usage, *child, &format!("{}{}", prefix, if last { "    " } else { "│   " }), out);
+        }
+    }
+}
+
+/// Listing in the style of `tree -h --du -F`: children sorted by name, directories with their total size.
+pub fn tree(fs: &FileSystem) -> String {
+    let usage = DiskUsage::new(fs);
+    let mut out = format!("[{:>5}]  /\n", human_size(usage.total()));
+    tree_lines(&usage, FileSystem::ROOT, "", &mut out);
+    let directories = fs.directories().count() - 1;
+    let files = fs.nodes().count() - directories - 1;
+    out.push_str(&format!("\n{} director{}, {} file{}\n", directories, if directories == 1 { "y" } else { "ies" },
+                          files, if files == 1 { "" } else { "s" }));
+    out
+}
+
+fn node_json(usage: &DiskUsage, id: NodeId) -> Value {
+    let fs = usage.filesystem();
+    match &fs.node(id).kind {
+        Kind::File { size } => json!({"name": fs.name(id), "type": "file", "size": size}),
+        Kind::Directory { children } => json!({
+            "name": fs.name(id),
+            "type": "directory",
+            "size": usage.size(id),
+            "children": children.iter().map(|child| node_json(usage, *child)).collect::<Vec<_>>(),
+        }),
+    }
+}
+
+/// Nested JSON objects with `name`, `type` (`file` or `directory`), `size` and the `children` of directories.
+pub fn to_json(fs: &FileSystem) -> Value {
+    node_json(&DiskUsage::new(fs), FileSystem::ROOT)
+}
+
+fn add_json(fs: &mut FileSystem, dir: NodeId, json: &Value) -> Result<()> {
+    let name = json["name"].as_str().ok_or_else(|| Error::invalid(format!("Expected a name in {}", json)))?;
+    match json["type"].as_str() {
+        Some("file") => {
+            let size = json["size"].as_u64().ok_or_else(|| Error::invalid(format!("Expected a size in {}", json)))?;
+            fs.add_file(dir, name, size)?;
+        }
+        Some("directory") => {
+            let child = fs.add_dir(dir, name)?;
+            add_children(fs, child, json)?;
+        }
+        _ => return Err(Error::invalid(format!("Expected type file or directory in {}", json))),
+    }
+    Ok(())
+}
+
+fn add_children(fs: &mut FileSystem, dir: NodeId, json: &Value) -> Result<()> {
+    match &json["children"] {
+        Value::Null => Ok(()),
+        Value::Array(children) => children.iter().try_for_each(|child| add_json(fs, dir, child)),
+        _ => Err(Error::invalid(format!("Expected an array of children in {}", json))),
+    }
+}
+
+/// Inverse of [`to_json`], the sizes of directories are ignored and `children` may be left out.
+pub fn from_json(text: &str) -> Result<FileSystem> {
+    let json: Value = serde_json::from_str(text).map_err(|e| Error::invalid(format!("Invalid JSON: {}", e)))?;
+    let mut fs = FileSystem::new();
+    add_children(&mut fs, FileSystem::ROOT, &json)?;
+    Ok(fs)
+}
+
+// a directory is an array of its own info object followed by its entries
+fn ncdu_entry(fs: &FileSystem, id: NodeId) -> Value {
+    match &fs.node(id).kind {
+        Kind::File { size } => json!({"name": fs.name(id), "asize": size, "dsize": size}),
+        Kind::Directory { children } => Value::Array(std::iter::once(json!({"name": fs.name(id)}))
+            .chain(children.iter().map(|child| ncdu_entry(fs, *child)))
+            .collect()),
+    }
+}
+
+/// ncdu's JSON export format, browse it with `ncdu -f <file>`.
+pub fn to_ncdu(fs: &FileSystem) -> Value {
+    json!([1, 2, {"progname": env!("CARGO_PKG_NAME"), "progver": env!("CARGO_PKG_VERSION")},
+           ncdu_entry(fs, FileSystem::ROOT)])
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::super::shell::interpret;
+    use super::*;
+
+    fn example() -> FileSystem {
+        interpret(&load_input("input/07.test.txt").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_human_size() {
+        assert_eq!(human_size(0), "0");
+        assert_eq!(human_size(1023), "1023");
+        assert_eq!(human_size(1024), "1.0K");
+        assert_eq!(human_size(1025), "1.1K");
+        assert_eq!(human_size(29116), "29K");
+        assert_eq!(human_size(48381165), "47M");
+        assert_eq!(human_size(3 << 30), "3.0G");
+    }
+
+    #[test]
+    fn test_tree() {
+        assert_eq!(tree(&example()), "\
+[  47M]  /
+├── [  93K]  a/
+│   ├── [  584]  e/
+│   │   └── [  584]  i
+│   ├── [  29K]  f
+│   ├── [ 2.5K]  g
+│   └── [  62K]  h.lst
+├── [  15M]  b.txt
+├── [ 8.2M]  c.dat
+└── [  24M]  d/
+    ├── [ 5.4M]  d.ext
+    ├── [ 7.7M]  d.log
+    ├── [ 3.9M]  j
+    └── [ 6.9M]  k
+
+3 directories, 10 files
+");
+    }
+
+    #[test]
+    fn test_json() {
+        let fs = example();
+        let json = to_json(&fs);
+        assert_eq!(json["size"], 48381165);
+        assert_eq!(json["children"][0], json!({"name": "a", "type": "directory", "size": 94853, "children": [
+            {"name": "e", "type": "directory", "size": 584, "children": [{"name": "i", "type": "file", "size": 584}]},
+            {"name": "f", "type": "file", "size": 29116},
+            {"name": "g", "type": "file", "size": 2557},
+            {"name": "h.lst", "type": "file", "size": 62596},
+        ]}));
+        let ncdu = to_ncdu(&fs);
+        assert_eq!((&ncdu[0], &ncdu[1]), (&json!(1), &json!(2)));
+        assert_eq!(ncdu[3][0], json!({"name": "/"}));
+        assert_eq!(ncdu[3][1][1][0], json!({"name": "e"}));
+        assert_eq!(ncdu[3][1][1][1], json!({"name": "i", "asize": 584, "dsize": 584}));
+        assert_eq!(ncdu[3][2], json!({"name": "b.txt", "asize": 14848514, "dsize": 14848514}));
+    }
+
+    #[test]
+    fn test_from_json() {
+        let json = to_json(&example());
+        assert_eq!(to_json(&from_json(&json.to_string()).unwrap()), json);
+        let fs = from_json(r#"{"children": [{"name": "a", "type": "directory"}, {"name": "b", "type": "file", "size": 3}]}"#).unwrap();
+        assert!(fs.is_dir(fs.lookup("/a").unwrap()));
+        assert_eq!(fs.size(FileSystem::ROOT), 3);
+        assert_eq!(from_json(r#"{"children": [{"name": "b", "type": "file"}]}"#).unwrap_err().to_string(),
+                   r#"Expected a size in {"name":"b","type":"file"}"#);
+        assert!(from_json(r#"{"children": [{"name": "b", "type": "link"}]}"#).is_err());
+        assert!(from_json("{").is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,236 @@
+use std::fs;
+use std::path::Path;
+
+use crate::{Error, Result};
+
+/// Index of a node in its [`FileSystem`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(pub(super) usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Kind {
+    File { size: u64 },
+    Directory { children: Vec<NodeId> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub name: String,
+    pub parent: Option<NodeId>,
+    pub kind: Kind,
+}
+
+/// Directory tree stored as an arena: nodes refer to each other by [`NodeId`],
+/// so there are no reference cycles between parents and children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        FileSystem::new()
+    }
+}
+
+impl FileSystem {
+    pub const ROOT: NodeId = NodeId(0);
+
+    /// File system containing just the empty root directory `/`.
+    pub fn new() -> FileSystem {
+        FileSystem { nodes: vec![Node { name: "/".to_string(), parent: None, kind: Kind::Directory { children: vec![] } }] }
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.node(id).name
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).parent
+    }
+
+    pub fn is_dir(&self, id: NodeId) -> bool {
+        matches!(self.node(id).kind, Kind::Directory { .. })
+    }
+
+    /// Entries of a directory in the order they were added, nothing for files.
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        match &self.node(id).kind {
+            Kind::Directory { children } => children,
+            Kind::File { .. } => &[],
+        }
+    }
+
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        self.children(dir).iter().copied().find(|child| self.name(*child) == name)
+    }
+
+    fn add(&mut self, dir: NodeId, name: &str, kind: Kind) -> Result<NodeId> {
+        if !self.is_dir(dir) {
+            return Err(Error::invalid(format!("{} is not a directory", self.path(dir))));
+        }
+        if name.is_empty() || name.contains('/') || name == "." || name == ".." {
+            return Err(Error::invalid(format!("Invalid name '{}'", name)));
+        }
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node { name: name.to_string(), parent: Some(dir), kind });
+        if let Kind::Directory { children } = &mut self.nodes[dir.0].kind {
+            children.push(id);
+        }
+        Ok(id)
+    }
+
+    /// Adds a subdirectory, or returns the existing one of that name.
+    pub fn add_dir(&mut self, dir: NodeId, name: &str) -> Result<NodeId> {
+        match self.child(dir, name) {
+            Some(existing) if self.is_dir(existing) => Ok(existing),
+            Some(existing) => Err(Error::invalid(format!("{} is a file, not a directory", self.path(existing)))),
+            None => self.add(dir, name, Kind::Directory { children: vec![] }),
+        }
+    }
+
+    /// Adds a file, adding it again with the same size returns the existing one.
+    pub fn add_file(&mut self, dir: NodeId, name: &str, size: u64) -> Result<NodeId> {
+        match self.child(dir, name) {
+            Some(existing) if self.node(existing).kind == Kind::File { size } => Ok(existing),
+            Some(existing) => Err(Error::invalid(format!("{} already exists with different contents", self.path(existing)))),
+            None => self.add(dir, name, Kind::File { size }),
+        }
+    }
+
+    /// Absolute path of a node, directories end with `/`.
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = vec![];
+        let mut current = id;
+        while let Some(parent) = self.parent(current) {
+            names.push(self.name(current));
+            current = parent;
+        }
+        names.reverse();
+        let mut path = format!("/{}", names.join("/"));
+        if self.is_dir(id) && id != FileSystem::ROOT {
+            path.push('/');
+        }
+        path
+    }
+
+    /// Resolves a path like `/a/e` or `a/e/` relative to `from`, `..` goes up one level.
+    pub fn lookup_from(&self, from: NodeId, path: &str) -> Option<NodeId> {
+        let start = if path.starts_with('/') { FileSystem::ROOT } else { from };
+        path.split('/')
+            .filter(|name| !name.is_empty() && *name != ".")
+            .try_fold(start, |current, name| match name {
+                ".." => self.parent(current),
+                name => self.child(current, name),
+            })
+    }
+
+    /// Resolves a path relative to the root directory.
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        self.lookup_from(FileSystem::ROOT, path)
+    }
+
+    /// All nodes, parents before their children.
+    pub fn nodes(&self) -> impl DoubleEndedIterator<Item=NodeId> {
+        (0..self.nodes.len()).map(NodeId)
+    }
+
+    pub fn directories(&self) -> impl Iterator<Item=NodeId> + '_ {
+        self.nodes().filter(|id| self.is_dir(*id))
+    }
+
+    /// Copies the names and sizes of the files below a directory on disk, symbolic links are skipped.
+    pub fn from_dir(path: impl AsRef<Path>) -> Result<FileSystem> {
+        let mut fs = FileSystem::new();
+        fs.copy_dir(path.as_ref(), FileSystem::ROOT)?;
+        Ok(fs)
+    }
+
+    fn copy_dir(&mut self, path: &Path, dir: NodeId) -> Result<()> {
+        let io_error = |source| Error::Io { path: path.to_path_buf(), source };
+        for entry in fs::read_dir(path).map_err(io_error)? {
+            let entry = entry.map_err(io_error)?;
+            let name = entry.file_name().into_string()
+                .map_err(|name| Error::invalid(format!("File name {:?} is not valid UTF-8", name)))?;
+            let file_type = entry.file_type().map_err(io_error)?;
+            if file_type.is_dir() {
+                let child = self.add_dir(dir, &name)?;
+                self.copy_dir(&entry.path(), child)?;
+            } else if file_type.is_file() {
+                self.add_file(dir, &name, entry.metadata().map_err(io_error)?.len())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Size of a file, or the total size of all files below a directory.
+    pub fn size(&self, id: NodeId) -> u64 {
+        match &self.node(id).kind {
+            Kind::File { size } => *size,
+            Kind::Directory { children } => children.iter().map(|child| self.size(*child)).sum(),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> FileSystem {
+        let mut fs = FileSystem::new();
+        let a = fs.add_dir(FileSystem::ROOT, "a").unwrap();
+        let e = fs.add_dir(a, "e").unwrap();
+        fs.add_file(e, "i", 584).unwrap();
+        fs.add_file(a, "f", 29116).unwrap();
+        fs.add_file(FileSystem::ROOT, "b.txt", 14848514).unwrap();
+        fs.add_dir(FileSystem::ROOT, "empty").unwrap();
+        fs
+    }
+
+    #[test]
+    fn test_lookup() {
+        let fs = example();
+        let e = fs.lookup("/a/e").unwrap();
+        assert_eq!(fs.path(e), "/a/e/");
+        assert_eq!(fs.lookup("a/e/"), Some(e));
+        assert_eq!(fs.lookup_from(e, "../f").map(|f| fs.path(f)), Some("/a/f".to_string()));
+        assert_eq!(fs.lookup_from(e, "/"), Some(FileSystem::ROOT));
+        assert_eq!(fs.lookup("/a/x"), None);
+        assert_eq!(fs.lookup(".."), None);
+    }
+
+    #[test]
+    fn test_kinds_and_sizes() {
+        let fs = example();
+        let empty = fs.lookup("/empty").unwrap();
+        assert!(fs.is_dir(empty) && fs.children(empty).is_empty());
+        assert!(!fs.is_dir(fs.lookup("/b.txt").unwrap()));
+        assert_eq!(fs.directories().count(), 4);
+        assert_eq!(fs.size(fs.lookup("/a").unwrap()), 29700);
+        assert_eq!(fs.size(FileSystem::ROOT), 14848514 + 29700);
+    }
+
+    #[test]
+    fn test_add() {
+        let mut fs = example();
+        let a = fs.lookup("/a").unwrap();
+        assert_eq!(fs.add_dir(FileSystem::ROOT, "a").unwrap(), a);
+        assert!(fs.add_dir(a, "f").is_err());
+        assert!(fs.add_file(a, "f", 29116).is_ok());
+        assert!(fs.add_file(a, "f", 1).is_err());
+        assert!(fs.add_file(fs.lookup("/a/f").unwrap(), "g", 1).is_err());
+        assert!(fs.add_dir(a, "..").is_err());
+    }
+
+    #[test]
+    fn test_from_dir() {
+        let fs = FileSystem::from_dir("src/days/day07").unwrap();
+        assert!(fs.lookup("/filesystem.rs").is_some_and(|id| fs.size(id) > 0));
+        assert!(FileSystem::from_dir("src/days/day07/missing").is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,101 @@
+use super::{FileSystem, NodeId};
+
+/// Sizes of all nodes of a file system like `du` reports them, computed once up front.
+#[derive(Debug, Clone)]
+pub struct DiskUsage<'a> {
+    fs: &'a FileSystem,
+    sizes: Vec<u64>,
+}
+
+impl<'a> DiskUsage<'a> {
+    pub fn new(fs: &'a FileSystem) -> DiskUsage<'a> {
+        let mut sizes = vec![0; fs.nodes().count()];
+        // children always come after their parent, so going backwards every node is complete before its parent
+        for id in fs.nodes().rev() {
+            if !fs.is_dir(id) {
+                sizes[id.0] = fs.size(id);
+            }
+            if let Some(parent) = fs.parent(id) {
+                sizes[parent.0] += sizes[id.0];
+            }
+        }
+        DiskUsage { fs, sizes }
+    }
+
+    pub fn filesystem(&self) -> &'a FileSystem {
+        self.fs
+    }
+
+    pub fn size(&self, id: NodeId) -> u64 {
+        self.sizes[id.0]
+    }
+
+    pub fn total(&self) -> u64 {
+        self.size(FileSystem::ROOT)
+    }
+
+    /// Size of the file or directory at an absolute path.
+    pub fn size_of(&self, path: &str) -> Option<u64> {
+        self.fs.lookup(path).map(|id| self.size(id))
+    }
+
+    /// Paths of all files and directories with their sizes, parents before their children.
+    pub fn paths(&self) -> impl Iterator<Item=(String, u64)> + '_ {
+        self.fs.nodes().map(|id| (self.fs.path(id), self.size(id)))
+    }
+
+    pub fn directories(&self) -> impl Iterator<Item=(NodeId, u64)> + '_ {
+        self.fs.directories().map(|id| (id, self.size(id)))
+    }
+
+    /// Directories whose total size matches the predicate, like `find -type d -size`.
+    pub fn find<'b>(&'b self, mut predicate: impl FnMut(u64) -> bool + 'b) -> impl Iterator<Item=(NodeId, u64)> + 'b {
+        self.directories().filter(move |(_, size)| predicate(*size))
+    }
+
+    /// The `n` largest directories, biggest first.
+    pub fn largest(&self, n: usize) -> Vec<(NodeId, u64)> {
+        let mut directories: Vec<_> = self.directories().collect();
+        directories.sort_by_key(|(id, size)| (std::cmp::Reverse(*size), *id));
+        directories.truncate(n);
+        directories
+    }
+
+    /// Bytes that have to be deleted so that `required` bytes are free on a disk of size `disk`.
+    pub fn missing(&self, disk: u64, required: u64) -> u64 {
+        (self.total() + required).saturating_sub(disk)
+    }
+
+    /// Smallest directory of at least `bytes`, `None` if even the root directory is smaller.
+    pub fn smallest_freeing(&self, bytes: u64) -> Option<(NodeId, u64)> {
+        self.find(|size| size >= bytes).min_by_key(|(id, size)| (*size, *id))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::super::shell::interpret;
+    use super::*;
+
+    #[test]
+    fn test_queries() {
+        let fs = interpret(&load_input("input/07.test.txt").unwrap()).unwrap();
+        let du = DiskUsage::new(&fs);
+        assert_eq!(du.total(), 48381165);
+        assert_eq!(du.size_of("/a/e"), Some(584));
+        assert_eq!(du.size_of("/d/k"), Some(7214296));
+        assert_eq!(du.size_of("/x"), None);
+        assert!(fs.nodes().all(|id| du.size(id) == fs.size(id)));
+        let small: Vec<_> = du.find(|size| size <= 100000).map(|(id, _)| fs.path(id)).collect();
+        assert_eq!(small, vec!["/a/", "/a/e/"]);
+        let largest: Vec<_> = du.largest(2).into_iter().map(|(id, size)| (fs.path(id), size)).collect();
+        assert_eq!(largest, vec![("/".to_string(), 48381165), ("/d/".to_string(), 24933642)]);
+        assert_eq!(du.missing(70000000, 30000000), 8381165);
+        assert_eq!(du.smallest_freeing(8381165).map(|(id, _)| fs.path(id)), Some("/d/".to_string()));
+        assert_eq!(du.smallest_freeing(48381166), None);
+        assert_eq!(du.paths().nth(1), Some(("/a/".to_string(), 94853)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,170 @@
+use std::collections::HashSet;
+
+use crate::{Error, Result, Source};
+
+use super::{FileSystem, Kind, NodeId};
+
+/// One line of `ls` output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Entry<'a> {
+    Dir(&'a str),
+    File(&'a str, u64),
+}
+
+impl Entry<'_> {
+    fn name(&self) -> &str {
+        match self {
+            Entry::Dir(name) | Entry::File(name, _) => name,
+        }
+    }
+}
+
+/// Terminal session replaying a transcript: the file system seen so far and the working directory.
+#[derive(Debug, Clone)]
+pub struct Shell {
+    fs: FileSystem,
+    cwd: NodeId,
+    listed: HashSet<NodeId>,
+}
+
+impl Default for Shell {
+    fn default() -> Self {
+        Shell::new()
+    }
+}
+
+impl Shell {
+    pub fn new() -> Shell {
+        Shell { fs: FileSystem::new(), cwd: FileSystem::ROOT, listed: HashSet::new() }
+    }
+
+    pub fn cwd(&self) -> NodeId {
+        self.cwd
+    }
+
+    pub fn filesystem(&self) -> &FileSystem {
+        &self.fs
+    }
+
+    pub fn into_filesystem(self) -> FileSystem {
+        self.fs
+    }
+
+    /// Changes to an absolute or relative directory, `..` at the root stays at the root.
+    pub fn cd(&mut self, path: &str) -> Result<()> {
+        let mut dir = if path.starts_with('/') { FileSystem::ROOT } else { self.cwd };
+        for name in path.split('/').filter(|name| !name.is_empty() && *name != ".") {
+            dir = match name {
+                ".." => self.fs.parent(dir).unwrap_or(FileSystem::ROOT),
+                name => self.fs.child(dir, name)
+                    .ok_or_else(|| Error::invalid(format!("Unknown directory '{}'", path)))?,
+            };
+            if !self.fs.is_dir(dir) {
+                return Err(Error::invalid(format!("'{}' is not a directory", path)));
+            }
+        }
+        self.cwd = dir;
+        Ok(())
+    }
+
+    /// Records the listing of the working directory. Listing a directory again must give the same entries.
+    pub fn ls(&mut self, entries: &[Entry]) -> Result<()> {
+        if !self.listed.insert(self.cwd) {
+            let fs = &self.fs;
+            let same = entries.len() == fs.children(self.cwd).len() && entries.iter().all(|entry| {
+                match (*entry, fs.child(self.cwd, entry.name())) {
+                    (Entry::Dir(_), Some(id)) => fs.is_dir(id),
+                    (Entry::File(_, size), Some(id)) => fs.node(id).kind == Kind::File { size },
+                    (_, None) => false,
+                }
+            });
+            return match same {
+                true => Ok(()),
+                false => Err(Error::invalid(format!("Listing of {} differs from the earlier one", self.fs.path(self.cwd)))),
+            };
+        }
+        for entry in entries {
+            match *entry {
+                Entry::Dir(name) => self.fs.add_dir(self.cwd, name)?,
+                Entry::File(name, size) => self.fs.add_file(self.cwd, name, size)?,
+            };
+        }
+        Ok(())
+    }
+}
+
+fn parse_entry<'a>(src: &Source, line: &'a str) -> Result<Entry<'a>> {
+    match line.strip_prefix("dir ") {
+        Some(name) => Ok(Entry::Dir(name)),
+        None => {
+            let (size, name) = src.split_once(line, " ")?;
+            Ok(Entry::File(name, src.parse(size)?))
+        }
+    }
+}
+
+/// Replays a transcript of `$ cd <path>` and `$ ls` commands, starting in the root directory.
+pub fn interpret(transcript: &str) -> Result<FileSystem> {
+    let src = Source::new(transcript);
+    let mut shell = Shell::new();
+    let mut lines = transcript.lines().peekable();
+    if lines.peek().is_none() {
+        return Err(Error::invalid("Empty transcript"));
+    }
+    while let Some(line) = lines.next() {
+        if let Some(path) = line.strip_prefix("$ cd ") {
+            shell.cd(path).map_err(|e| src.error(path, e))?;
+        } else if line == "$ ls" {
+            let mut entries = vec![];
+            while let Some(output) = lines.next_if(|l| !l.starts_with('$')) {
+                entries.push(parse_entry(&src, output)?);
+            }
+            shell.ls(&entries).map_err(|e| src.error(line, e))?;
+        } else if line.starts_with('$') {
+            return Err(src.error(line, format!("Unknown command '{}'", line)));
+        } else {
+            return Err(src.error(line, "Output without a preceding '$ ls'"));
+        }
+    }
+    Ok(shell.into_filesystem())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigation() {
+        let fs = interpret("$ cd ..\n$ ls\ndir a\n$ cd a\n$ ls\ndir b\n$ cd /a/b\n$ ls\n1 x\n\
+                            $ cd /\n$ cd a/b/../b\n$ cd ../..\n$ cd ..\n$ ls\ndir a\n").unwrap();
+        assert_eq!(fs.size(FileSystem::ROOT), 1);
+        assert_eq!(fs.lookup("/a/b/x").map(|x| fs.size(x)), Some(1));
+        let mut shell = Shell::new();
+        shell.ls(&[Entry::Dir("a")]).unwrap();
+        shell.cd("a/../a/").unwrap();
+        assert_eq!(shell.filesystem().path(shell.cwd()), "/a/");
+    }
+
+    #[test]
+    fn test_repeated_listing() {
+        let fs = interpret("$ ls\n10 f\ndir a\n$ cd a\n$ ls\n5 g\n$ cd ..\n$ ls\ndir a\n10 f\n").unwrap();
+        assert_eq!(fs.size(FileSystem::ROOT), 15);
+        assert_eq!(fs.nodes().count(), 4);
+    }
+
+    #[test]
+    fn test_inconsistent_transcripts() {
+        let error = |transcript: &str| interpret(transcript).unwrap_err().to_string();
+        assert_eq!(error("$ ls\n10 f\n$ ls\n11 f\n"),
+                   "line 3, column 1: Listing of / differs from the earlier one\n  $ ls\n  ^");
+        assert_eq!(error("$ ls\n10 f\n$ ls\n10 f\n1 g\n").lines().next(),
+                   Some("line 3, column 1: Listing of / differs from the earlier one"));
+        assert_eq!(error("$ ls\n10 f\n$ cd f\n").lines().next(), Some("line 3, column 6: 'f' is not a directory"));
+        assert_eq!(error("$ ls\n10 f\ndir f\n").lines().next(), Some("line 1, column 1: /f is a file, not a directory"));
+        assert_eq!(error("10 f\n").lines().next(), Some("line 1, column 1: Output without a preceding '$ ls'"));
+        assert_eq!(error("$ rm -rf /\n").lines().next(), Some("line 1, column 1: Unknown command '$ rm -rf /'"));
+        assert!(error("$ ls\nten f\n").starts_with("line 2, column 1: Unable to parse 'ten'"));
+        assert_eq!(error(""), "Empty transcript");
+    }
+}
\ No newline at end of file
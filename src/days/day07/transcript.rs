@@ -0,0 +1,136 @@
+use std::collections::VecDeque;
+
+use super::{FileSystem, Kind, NodeId};
+
+/// Order in which the directories are visited and listed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Traversal {
+    /// Each directory is followed by its subdirectories, like the puzzle input.
+    #[default]
+    DepthFirst,
+    /// All directories of one level before any of the next level.
+    BreadthFirst,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Options {
+    pub traversal: Traversal,
+    /// List and visit entries sorted by name instead of in the order they were added.
+    pub sorted: bool,
+    /// Change directories with one `$ cd /a/b` instead of a `$ cd` per level.
+    pub absolute: bool,
+}
+
+fn entries(fs: &FileSystem, dir: NodeId, options: &Options) -> Vec<NodeId> {
+    let mut children = fs.children(dir).to_vec();
+    if options.sorted {
+        children.sort_by(|a, b| fs.name(*a).cmp(fs.name(*b)));
+    }
+    children
+}
+
+fn ancestors(fs: &FileSystem, id: NodeId) -> Vec<NodeId> {
+    let mut ancestors = vec![id];
+    while let Some(parent) = fs.parent(ancestors[ancestors.len() - 1]) {
+        ancestors.push(parent);
+    }
+    ancestors.reverse();
+    ancestors
+}
+
+fn cd(fs: &FileSystem, from: NodeId, to: NodeId, options: &Options, out: &mut Vec<String>) {
+    if from == to {
+        return;
+    }
+    if options.absolute {
+        out.push(format!("$ cd {}", fs.path(to)));
+        return;
+    }
+    let (from, to) = (ancestors(fs, from), ancestors(fs, to));
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    out.extend((common..from.len()).map(|_| "$ cd ..".to_string()));
+    out.extend(to[common..].iter().map(|dir| format!("$ cd {}", fs.name(*dir))));
+}
+
+fn ls(fs: &FileSystem, dir: NodeId, options: &Options, out: &mut Vec<String>) {
+    out.push("$ ls".to_string());
+    out.extend(entries(fs, dir, options).into_iter().map(|id| match fs.node(id).kind {
+        Kind::File { size } => format!("{} {}", size, fs.name(id)),
+        Kind::Directory { .. } => format!("dir {}", fs.name(id)),
+    }));
+}
+
+/// Terminal output that explores every directory of `fs` once, the inverse of `prepare_input`.
+pub fn transcript(fs: &FileSystem, options: &Options) -> String {
+    let mut out = vec!["$ cd /".to_string()];
+    let mut cwd = FileSystem::ROOT;
+    let mut pending = VecDeque::from([FileSystem::ROOT]);
+    while let Some(dir) = pending.pop_front() {
+        cd(fs, cwd, dir, options, &mut out);
+        cwd = dir;
+        ls(fs, dir, options, &mut out);
+        let subdirectories = entries(fs, dir, options).into_iter().filter(|id| fs.is_dir(*id));
+        match options.traversal {
+            Traversal::DepthFirst => subdirectories.rev().for_each(|id| pending.push_front(id)),
+            Traversal::BreadthFirst => pending.extend(subdirectories),
+        }
+    }
+    out.push(String::new());
+    out.join("\n")
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::super::export::{to_json, tree};
+    use super::super::shell::interpret;
+    use super::*;
+
+    #[test]
+    fn test_transcript() {
+        let data = load_input("input/07.test.txt").unwrap();
+        let fs = interpret(&data).unwrap();
+        assert_eq!(transcript(&fs, &Options::default()).trim_end(), data.trim_end());
+        let options = Options { traversal: Traversal::BreadthFirst, sorted: true, absolute: true };
+        assert_eq!(transcript(&fs, &options).lines().filter(|l| l.starts_with("$ cd")).collect::<Vec<_>>(),
+                   vec!["$ cd /", "$ cd /a/", "$ cd /d/", "$ cd /a/e/"]);
+    }
+
+    // file system with `n` random entries from a xorshift generator
+    fn random_filesystem(mut seed: u64, n: usize) -> FileSystem {
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+        let mut fs = FileSystem::new();
+        let mut dirs = vec![FileSystem::ROOT];
+        for i in 0..n {
+            let dir = dirs[next() as usize % dirs.len()];
+            if next() % 3 == 0 {
+                dirs.push(fs.add_dir(dir, &format!("d{}", next() % 50)).unwrap());
+            } else {
+                fs.add_file(dir, &format!("f{} {}.txt", i, next() % 10), next() % 1000000).unwrap();
+            }
+        }
+        fs
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for seed in 1..20 {
+            let fs = random_filesystem(seed, 200);
+            for traversal in [Traversal::DepthFirst, Traversal::BreadthFirst] {
+                for absolute in [false, true] {
+                    let unsorted = Options { traversal, sorted: false, absolute };
+                    assert_eq!(to_json(&interpret(&transcript(&fs, &unsorted)).unwrap()), to_json(&fs));
+                    let sorted = Options { sorted: true, ..unsorted };
+                    assert_eq!(tree(&interpret(&transcript(&fs, &sorted)).unwrap()), tree(&fs));
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+use crate::{Direction, Grid, Result, Solution};
+
+fn prepare_input(s: String) -> Result<Grid<usize>> {
+    Grid::parse(&s, |tree| tree.to_digit(10)
+        .map(|height| height as usize)
+        .ok_or_else(|| format!("Invalid tree height '{}'", tree)))
+}
+
+fn part1(forest: &Grid<usize>) -> usize {
+    forest.cells()
+        // trees on the border see the edge in at least one direction
+        .filter(|(pos, height)| Direction::ALL.iter().any(|d| forest.ray(*pos, d.offset()).all(|(_, t)| t < height)))
+        .count()
+}
+
+fn part2(forest: &Grid<usize>) -> usize {
+    forest.cells()
+        .map(|(pos, height)| Direction::ALL.iter()
+            .map(|d| visible_trees(forest.ray(pos, d.offset()).map(|(_, t)| t), *height))
+            .product())
+        .max()
+        .unwrap()
+}
+
+fn visible_trees<'a>(trees: impl Iterator<Item=&'a usize>, height: usize) -> usize {
+    let mut visible = 0;
+    for tree in trees {
+        visible += 1;
+        if *tree >= height { break }
+    }
+    visible
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/08.test.txt").unwrap();
+        let forest = prepare_input(data).unwrap();
+        assert_eq!(part1(&forest), 21);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/08.test.txt").unwrap();
+        let forest = prepare_input(data).unwrap();
+        assert_eq!(part2(&forest), 8);
+    }
+}
\ No newline at end of file
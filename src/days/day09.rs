@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+use crate::{Direction, Param, Params, Point, Result, Solution, Source};
+
+// direction and number of steps of each motion
+type Motions = Vec<(Direction, usize)>;
+
+fn prepare_input(s: String) -> Result<Motions> {
+    let src = Source::new(&s);
+    let mut motions: Motions = vec![];
+    for line in s.lines() {
+        let (d, n) = src.split_once(line, " ")?;
+        let direction = match d {
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            d => return Err(src.error(d, format!("Unknown direction '{}'", d)))
+        };
+        let steps: usize = src.parse(n)?;
+        // the rope moves on i32 coordinates
+        if steps > i32::MAX as usize {
+            return Err(src.error(n, format!("At most {} steps per motion", i32::MAX)));
+        }
+        motions.push((direction, steps));
+    }
+    Ok(motions)
+}
+
+// single steps of the head
+fn steps(motions: &[(Direction, usize)]) -> impl Iterator<Item=Direction> + '_ {
+    motions.iter().flat_map(|(direction, n)| std::iter::repeat_n(*direction, *n))
+}
+
+fn check_and_move_tail(h: Point, t: &mut Point) {
+    if h.chebyshev(*t) > 1 {
+        // move tail one step in one of 8 directions towards the head
+        *t += (h - *t).signum();
+    }
+}
+
+fn part1(motions: &[(Direction, usize)]) -> usize {
+    let mut head = Point::ORIGIN;
+    let mut tail = Point::ORIGIN;
+    let mut reached = HashSet::from([Point::ORIGIN]);
+    for d in steps(motions) {
+        head += d.offset();
+        check_and_move_tail(head, &mut tail); // move tail
+        reached.insert(tail);
+    }
+    reached.len()
+}
+
+fn part2(motions: &[(Direction, usize)], length: usize) -> usize {
+    let mut knots = vec![Point::ORIGIN; length];
+    let mut reached = HashSet::from([Point::ORIGIN]);
+    for d in steps(motions) {
+        knots[0] += d.offset();
+        for i in 0..length - 1 { // move the other parts of the rope, one after the other
+            check_and_move_tail(knots[i], &mut knots[i + 1]);
+        }
+        reached.insert(knots[length - 1]);
+    }
+    reached.len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Motions;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        Self::part2_with(input, &Params::new(Self::PARAMS))
+    }
+
+    const PARAMS: &'static [Param] = &[Param { name: "knots", default: 10, min: 2, max: 1000000 }];
+
+    fn part2_with(input: &Self::Input, params: &Params) -> Self::Answer2 {
+        part2(input, params.get("knots") as usize)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/09.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part1(&input), 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/09.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part2(&input, 10), 1);
+        assert_eq!(part2(&input, 2), part1(&input));
+    }
+
+    #[test]
+    fn test_part2_large() {
+        let data = load_input("input/09_larger.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part2(&input, 10), 36);
+    }
+
+    #[test]
+    fn test_steps() {
+        let input = prepare_input("R 3\nU 1".to_string()).unwrap();
+        assert_eq!(steps(&input).collect::<Vec<_>>(),
+                   vec![Direction::Right, Direction::Right, Direction::Right, Direction::Up]);
+        let error = prepare_input("R 99999999999999999".to_string()).unwrap_err();
+        assert!(error.to_string().starts_with("line 1, column 3: At most 2147483647 steps per motion"));
+    }
+}
\ No newline at end of file
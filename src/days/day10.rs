@@ -0,0 +1,110 @@
+pub use cpu::{Cpu, Observer, sample, Tick};
+pub use crt::{Crt, Frame, to_pbm, to_pgm, to_text};
+pub use isa::{Instruction, InstructionSet, Opcode, Operand, OperandKind, Program, Registers};
+pub use ocr::recognize;
+
+use crate::{Error, Param, Params, Result, Solution};
+
+pub mod cpu;
+pub mod crt;
+pub mod isa;
+pub mod ocr;
+
+fn prepare_input(s: String) -> Result<Program> {
+    InstructionSet::puzzle().assemble(&s)
+}
+
+/// Sum of the signal strengths (cycle times X) during `first`, `first + every`, ... up to `last`.
+fn part1(program: &Program, first: u64, every: u64, last: u64) -> i64 {
+    sample(program, |cycle| cycle >= first && (cycle - first).is_multiple_of(every) && cycle <= last)
+        .iter()
+        .map(|tick| (tick.cycle as i64).wrapping_mul(tick.x()))
+        .fold(0, i64::wrapping_add)
+}
+
+
+/// The letters on the screen, errors show the screen along with the reason it could not be read.
+fn part2(program: &Program, crt: &Crt) -> Result<String> {
+    let screen = to_text(&crt.draw(program));
+    recognize(&screen).map_err(|e| Error::invalid(format!("{}\n{}", e, screen)))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Program;
+    type Answer1 = i64;
+    type Answer2 = Result<String>;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        Self::part1_with(input, &Params::new(Self::PARAMS))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        Self::part2_with(input, &Params::new(Self::PARAMS))
+    }
+
+    const PARAMS: &'static [Param] = &[
+        Param { name: "first", default: 20, min: 1, max: i64::MAX },
+        Param { name: "every", default: 40, min: 1, max: i64::MAX },
+        Param { name: "last", default: 220, min: 0, max: i64::MAX },
+        Param { name: "width", default: 40, min: 1, max: 10000 },
+        Param { name: "height", default: 6, min: 1, max: 10000 },
+        Param { name: "sprite", default: 3, min: 1, max: 10000 },
+    ];
+
+    fn part1_with(input: &Self::Input, params: &Params) -> Self::Answer1 {
+        part1(input, params.get("first") as u64, params.get("every") as u64, params.get("last") as u64)
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> Self::Answer2 {
+        part2(input, &Crt::from_params(params))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/10.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part1(&input, 20, 40, 220), 13140);
+        assert_eq!(part1(&input, 20, 1, 20), 420);
+        // registers wrap around like in the extended instruction set
+        let program = prepare_input(format!("addx {}\naddx 1\nnoop\nnoop", i64::MAX)).unwrap();
+        assert_eq!(part1(&program, 5, 1, 5), (i64::MIN + 1).wrapping_mul(5));
+    }
+
+    #[test]
+    fn test_part1_small() {
+        let data = load_input("input/10_small.test.txt").unwrap();
+        let program = prepare_input(data).unwrap();
+        let mut cpu = Cpu::new(&program);
+        let after: Vec<_> = (0..5).map(|_| cpu.step().map(|_| cpu.x())).collect();
+        assert_eq!(after, vec![Some(1), Some(1), Some(4), Some(4), Some(-1)]); // after cycles 1 to 5
+    }
+
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/10.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(to_text(&Crt::default().draw(&input)),
+                   "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....\n".to_string());
+        assert!(part2(&input, &Crt::default()).unwrap_err().to_string().starts_with("Unknown glyph 1 at column 1:\n##..\n"));
+    }
+}
\ No newline at end of file
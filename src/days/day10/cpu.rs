@@ -0,0 +1,135 @@
+use super::{Program, Registers};
+
+/// State during a clock cycle, e.g. "during the 20th cycle, register X has the value 21".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tick {
+    /// Number of the cycle, starting at 1.
+    pub cycle: u64,
+    pub registers: Registers,
+}
+
+impl Tick {
+    /// Value of the first register, X in the puzzle.
+    pub fn x(&self) -> i64 {
+        self.registers[0]
+    }
+}
+
+/// Gets called during every cycle while the CPU runs.
+pub trait Observer {
+    fn during(&mut self, tick: Tick);
+}
+
+impl<F: FnMut(Tick)> Observer for F {
+    fn during(&mut self, tick: Tick) {
+        self(tick)
+    }
+}
+
+/// CPU executing a program cycle by cycle, iterating over it yields a [`Tick`] per cycle until it halts.
+#[derive(Debug, Clone)]
+pub struct Cpu<'a> {
+    program: &'a Program,
+    pc: usize,
+    // cycles already spent on the instruction at `pc`
+    busy: u32,
+    cycle: u64,
+    registers: Registers,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(program: &'a Program) -> Cpu<'a> {
+        Cpu { program, pc: 0, busy: 0, cycle: 0, registers: program.isa.initial() }
+    }
+
+    pub fn x(&self) -> i64 {
+        self.registers[0]
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    /// Number of completed cycles.
+    pub fn cycle(&self) -> u64 {
+        self.cycle
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.pc >= self.program.len()
+    }
+
+    /// Runs a single cycle, `None` once the program is finished.
+    pub fn step(&mut self) -> Option<Tick> {
+        if self.is_halted() {
+            return None;
+        }
+        self.cycle += 1;
+        let tick = Tick { cycle: self.cycle, registers: self.registers };
+        self.busy += 1;
+        if self.busy == self.program.cycles(self.pc) {
+            self.program.execute(self.pc, &mut self.registers);
+            self.pc += 1;
+            self.busy = 0;
+        }
+        Some(tick)
+    }
+
+    /// Runs until the program is finished and returns the number of cycles it took.
+    pub fn run(&mut self, observer: &mut impl Observer) -> u64 {
+        while let Some(tick) = self.step() {
+            observer.during(tick);
+        }
+        self.cycle
+    }
+}
+
+impl Iterator for Cpu<'_> {
+    type Item = Tick;
+
+    fn next(&mut self) -> Option<Tick> {
+        self.step()
+    }
+}
+
+/// States during the cycles `wanted` accepts until the program halts.
+pub fn sample(program: &Program, mut wanted: impl FnMut(u64) -> bool) -> Vec<Tick> {
+    Cpu::new(program).filter(|tick| wanted(tick.cycle)).collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::super::InstructionSet;
+    use super::*;
+
+    #[test]
+    fn test_cycles() {
+        let program = InstructionSet::puzzle().assemble("noop\naddx 3\naddx -5").unwrap();
+        let mut cpu = Cpu::new(&program);
+        let mut trace = vec![];
+        assert_eq!(cpu.run(&mut |tick: Tick| trace.push(format!("during cycle {}, X = {}", tick.cycle, tick.x()))), 5);
+        assert_eq!(trace, vec!["during cycle 1, X = 1", "during cycle 2, X = 1", "during cycle 3, X = 1",
+                               "during cycle 4, X = 4", "during cycle 5, X = 4"]);
+        assert!(cpu.is_halted());
+        assert_eq!((cpu.x(), cpu.step()), (-1, None));
+    }
+
+    #[test]
+    fn test_long_program() {
+        let program = InstructionSet::puzzle().assemble(&"addx 1\nnoop\n".repeat(300)).unwrap();
+        assert_eq!(Cpu::new(&program).count(), 900);
+        let xs: Vec<_> = sample(&program, |cycle| [899, 3, 1000, 300].contains(&cycle)).iter()
+            .map(|tick| (tick.cycle, tick.x()))
+            .collect();
+        assert_eq!(xs, vec![(3, 2), (300, 101), (899, 300)]);
+    }
+
+    #[test]
+    fn test_extended() {
+        let program = InstructionSet::extended().assemble("set y 3\nmul y y # 3 cycles\naddx y\nsub x -2\n").unwrap();
+        let mut cpu = Cpu::new(&program);
+        assert_eq!(cpu.by_ref().map(|tick| tick.registers[1]).collect::<Vec<_>>(), vec![0, 3, 3, 3, 9, 9, 9]);
+        assert_eq!(cpu.registers(), &[12, 9, 0, 0]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,166 @@
+use crate::{Grid, Params, Point};
+
+use super::{Cpu, Program};
+
+/// Geometry of the CRT: the beam draws one pixel per cycle, row by row, while the sprite follows register X.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crt {
+    pub width: usize,
+    pub height: usize,
+    /// Width of the sprite in pixels, X is its middle (the left one of the two middles for even widths).
+    pub sprite: usize,
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Crt { width: 40, height: 6, sprite: 3 }
+    }
+}
+
+/// The screen after one cycle of drawing.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub crt: Crt,
+    pub cycle: u64,
+    /// Pixel drawn during the cycle.
+    pub beam: Point,
+    /// Value of X during the cycle.
+    pub x: i64,
+    /// Pixels the beam has not reached yet are `None`.
+    pub screen: Grid<Option<bool>>,
+}
+
+impl Crt {
+    /// Geometry from the `width`, `height` and `sprite` parameters of the day.
+    pub fn from_params(params: &Params) -> Crt {
+        Crt {
+            width: params.get("width") as usize,
+            height: params.get("height") as usize,
+            sprite: params.get("sprite") as usize,
+        }
+    }
+
+    /// Whether the sprite covers `column` while X is `x`.
+    pub fn covers(&self, column: i64, x: i64) -> bool {
+        let left = x - (self.sprite as i64 - 1) / 2;
+        (left..left + self.sprite as i64).contains(&column)
+    }
+
+    fn beam(&self, cycle: u64) -> Point {
+        let pixel = (cycle - 1) as usize;
+        Point::new((pixel % self.width) as i32, (pixel / self.width) as i32)
+    }
+
+    /// The picture drawn by the program, pixels the beam never reaches stay dark.
+    pub fn draw(&self, program: &Program) -> Grid<bool> {
+        let mut screen = Grid::new(self.width, self.height, false);
+        for tick in Cpu::new(program).take(self.width * self.height) {
+            let beam = self.beam(tick.cycle);
+            screen.set(beam, self.covers(beam.x as i64, tick.x()));
+        }
+        screen
+    }
+
+    /// The picture after every cycle while it is being drawn.
+    pub fn frames<'a>(&self, program: &'a Program) -> impl Iterator<Item=Frame> + 'a {
+        let crt = *self;
+        let mut screen = Grid::new(crt.width, crt.height, None);
+        Cpu::new(program).take(crt.width * crt.height).map(move |tick| {
+            let beam = crt.beam(tick.cycle);
+            screen.set(beam, Some(crt.covers(beam.x as i64, tick.x())));
+            Frame { crt, cycle: tick.cycle, beam, x: tick.x(), screen: screen.clone() }
+        })
+    }
+}
+
+/// `#` for lit and `.` for dark pixels, the way the puzzle shows the screen.
+pub fn to_text(screen: &Grid<bool>) -> String {
+    screen.render(|_, lit| if lit == Some(&true) { '#' } else { '.' })
+}
+
+/// Plain PBM image, lit pixels are white on black like on the CRT.
+pub fn to_pbm(screen: &Grid<bool>) -> String {
+    let mut image = format!("P1\n{} {}\n", screen.width(), screen.height());
+    for y in 0..screen.height() as i32 {
+        let row: Vec<_> = screen.row(y).map(|(_, lit)| if *lit { "0" } else { "1" }).collect();
+        image.push_str(&row.join(" "));
+        image.push('\n');
+    }
+    image
+}
+
+/// Plain PGM image with lit pixels white and dark ones black.
+pub fn to_pgm(screen: &Grid<bool>) -> String {
+    let mut image = format!("P2\n{} {}\n1\n", screen.width(), screen.height());
+    for y in 0..screen.height() as i32 {
+        let row: Vec<_> = screen.row(y).map(|(_, lit)| if *lit { "1" } else { "0" }).collect();
+        image.push_str(&row.join(" "));
+        image.push('\n');
+    }
+    image
+}
+
+impl Frame {
+    fn sprite_at(&self, pos: Point) -> bool {
+        pos.y == self.beam.y && self.crt.covers(pos.x as i64, self.x)
+    }
+
+    /// The cycle, the sprite on the beam's row and the screen so far, pixels not drawn yet are blank.
+    pub fn to_text(&self) -> String {
+        let sprite: String = (0..self.crt.width as i64)
+            .map(|x| if self.crt.covers(x, self.x) { '#' } else { '.' })
+            .collect();
+        format!("During cycle {}: X = {}, the beam draws {}\nSprite position: {}\n{}", self.cycle, self.x, self.beam,
+                sprite, self.screen.render(|_, pixel| match pixel {
+                    Some(Some(true)) => '#',
+                    Some(Some(false)) => '.',
+                    _ => ' ',
+                }))
+    }
+
+    /// Plain PGM image: lit pixels white, dark ones dark gray and pixels not drawn yet black.
+    /// The sprite shows as light gray on the beam's row wherever no lit pixel is.
+    pub fn to_pgm(&self) -> String {
+        let mut image = format!("P2\n{} {}\n3\n", self.screen.width(), self.screen.height());
+        for y in 0..self.screen.height() as i32 {
+            let row: Vec<_> = self.screen.row(y)
+                .map(|(pos, pixel)| match pixel {
+                    Some(true) => "3",
+                    _ if self.sprite_at(pos) => "2",
+                    Some(false) => "1",
+                    None => "0",
+                })
+                .collect();
+            image.push_str(&row.join(" "));
+            image.push('\n');
+        }
+        image
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::super::InstructionSet;
+    use super::*;
+
+    #[test]
+    fn test_geometry() {
+        let program = InstructionSet::puzzle().assemble("addx 2\naddx 3\nnoop\naddx -10").unwrap();
+        let crt = Crt { width: 3, height: 2, sprite: 1 };
+        assert_eq!(to_text(&crt.draw(&program)), ".#.\n...\n");
+        let wide = Crt { width: 4, height: 2, sprite: 4 };
+        assert_eq!(to_text(&wide.draw(&program)), "####\n....\n");
+        assert_eq!(to_pbm(&crt.draw(&program)), "P1\n3 2\n1 0 1\n1 1 1\n");
+        assert_eq!(to_pgm(&crt.draw(&program)), "P2\n3 2\n1\n0 1 0\n0 0 0\n");
+    }
+
+    #[test]
+    fn test_frames() {
+        let program = InstructionSet::puzzle().assemble("noop\nnoop\nnoop\naddx 1\nnoop").unwrap();
+        let frames: Vec<_> = Crt { width: 4, height: 2, sprite: 3 }.frames(&program).collect();
+        assert_eq!(frames.len(), 6);
+        assert_eq!(frames[2].to_text(), "During cycle 3: X = 1, the beam draws (2, 0)\nSprite position: ###.\n### \n    \n");
+        assert_eq!(frames[4].to_pgm(), "P2\n4 2\n3\n3 3 3 1\n3 2 2 0\n");
+    }
+}
\ No newline at end of file
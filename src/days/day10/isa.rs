@@ -0,0 +1,249 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{Result, Source};
+
+/// Size of the register file, instruction sets may name up to this many registers.
+pub const REGISTERS: usize = 4;
+
+pub type Registers = [i64; REGISTERS];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperandKind {
+    /// Has to name a register.
+    Register,
+    /// A register or a number.
+    Value,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(usize),
+    Immediate(i64),
+}
+
+impl Operand {
+    pub fn value(self, registers: &Registers) -> i64 {
+        match self {
+            Operand::Register(r) => registers[r],
+            Operand::Immediate(value) => value,
+        }
+    }
+
+    /// Index of the register, operands of kind [`OperandKind::Register`] always are one.
+    pub fn register(self) -> usize {
+        match self {
+            Operand::Register(r) => r,
+            Operand::Immediate(_) => panic!("Operand {:?} is not a register", self),
+        }
+    }
+}
+
+/// Definition of an opcode: how it is written, how many cycles it takes and what it does at the end of the last one.
+#[derive(Debug, Clone)]
+pub struct Opcode {
+    pub mnemonic: &'static str,
+    pub cycles: u32,
+    pub operands: &'static [OperandKind],
+    pub execute: fn(&mut Registers, &[Operand]),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    /// Index into the opcodes of the instruction set.
+    pub opcode: usize,
+    pub operands: Vec<Operand>,
+}
+
+/// Registers with their initial values and the opcodes a CPU understands.
+#[derive(Debug, Clone)]
+pub struct InstructionSet {
+    registers: Vec<(&'static str, i64)>,
+    opcodes: Vec<Opcode>,
+}
+
+impl InstructionSet {
+    /// Instruction set without any opcodes, the first register is the one the CRT follows.
+    pub fn new(registers: &[(&'static str, i64)]) -> InstructionSet {
+        assert!(!registers.is_empty() && registers.len() <= REGISTERS, "Between 1 and {} registers required", REGISTERS);
+        InstructionSet { registers: registers.to_vec(), opcodes: vec![] }
+    }
+
+    pub fn with(mut self, opcode: Opcode) -> InstructionSet {
+        assert!(self.opcode(opcode.mnemonic).is_none(), "Duplicate opcode {}", opcode.mnemonic);
+        assert!(opcode.cycles > 0, "Opcode {} has to take at least one cycle", opcode.mnemonic);
+        self.opcodes.push(opcode);
+        self
+    }
+
+    /// `noop` and `addx <value>` of the puzzle, operating on register `x` which starts at 1.
+    pub fn puzzle() -> InstructionSet {
+        InstructionSet::new(&[("x", 1)])
+            .with(Opcode { mnemonic: "noop", cycles: 1, operands: &[], execute: |_, _| {} })
+            .with(Opcode {
+                mnemonic: "addx",
+                cycles: 2,
+                operands: &[OperandKind::Value],
+                execute: |r, ops| r[0] = r[0].wrapping_add(ops[0].value(r)),
+            })
+    }
+
+    /// The puzzle's instructions plus registers `y`, `z` and `w` and a few arithmetic opcodes on any register.
+    pub fn extended() -> InstructionSet {
+        const BINARY: &[OperandKind] = &[OperandKind::Register, OperandKind::Value];
+        let mut isa = InstructionSet::puzzle();
+        isa.registers.extend([("y", 0), ("z", 0), ("w", 0)]);
+        isa.with(Opcode { mnemonic: "set", cycles: 1, operands: BINARY, execute: |r, ops| r[ops[0].register()] = ops[1].value(r) })
+            .with(Opcode {
+                mnemonic: "add",
+                cycles: 1,
+                operands: BINARY,
+                execute: |r, ops| r[ops[0].register()] = r[ops[0].register()].wrapping_add(ops[1].value(r)),
+            })
+            .with(Opcode {
+                mnemonic: "sub",
+                cycles: 1,
+                operands: BINARY,
+                execute: |r, ops| r[ops[0].register()] = r[ops[0].register()].wrapping_sub(ops[1].value(r)),
+            })
+            .with(Opcode {
+                mnemonic: "mul",
+                cycles: 3,
+                operands: BINARY,
+                execute: |r, ops| r[ops[0].register()] = r[ops[0].register()].wrapping_mul(ops[1].value(r)),
+            })
+    }
+
+    pub fn registers(&self) -> impl Iterator<Item=&'static str> + '_ {
+        self.registers.iter().map(|(name, _)| *name)
+    }
+
+    pub fn register(&self, name: &str) -> Option<usize> {
+        self.registers().position(|register| register == name)
+    }
+
+    /// Values of the registers when a program starts.
+    pub fn initial(&self) -> Registers {
+        let mut registers = [0; REGISTERS];
+        for (register, (_, value)) in registers.iter_mut().zip(&self.registers) {
+            *register = *value;
+        }
+        registers
+    }
+
+    pub fn opcodes(&self) -> &[Opcode] {
+        &self.opcodes
+    }
+
+    pub fn opcode(&self, mnemonic: &str) -> Option<usize> {
+        self.opcodes.iter().position(|opcode| opcode.mnemonic == mnemonic)
+    }
+
+    fn operand(&self, src: &Source, token: &str, kind: OperandKind) -> Result<Operand> {
+        if let Some(r) = self.register(token) {
+            return Ok(Operand::Register(r));
+        }
+        match (kind, token.parse()) {
+            (OperandKind::Value, Ok(value)) => Ok(Operand::Immediate(value)),
+            (OperandKind::Register, Ok(_)) => Err(src.error(token, format!("Expected a register instead of '{}'", token))),
+            (_, Err(_)) => Err(src.error(token, format!("Unknown register or invalid number '{}'", token))),
+        }
+    }
+
+    /// Parses one instruction per line, empty lines and everything after `#` are ignored.
+    pub fn assemble(self, text: &str) -> Result<Program> {
+        let src = Source::new(text);
+        let mut instructions = vec![];
+        for line in text.lines() {
+            let code = line.split_once('#').map_or(line, |(code, _)| code);
+            let mut tokens = code.split_whitespace();
+            let Some(mnemonic) = tokens.next() else { continue };
+            let opcode = self.opcode(mnemonic)
+                .ok_or_else(|| src.error(mnemonic, format!("Unknown instruction '{}'", mnemonic)))?;
+            let tokens: Vec<_> = tokens.collect();
+            let kinds = self.opcodes[opcode].operands;
+            if tokens.len() != kinds.len() {
+                return Err(src.error(mnemonic, format!("{} expects {} operand{}, found {}", mnemonic, kinds.len(),
+                                                       if kinds.len() == 1 { "" } else { "s" }, tokens.len())));
+            }
+            let operands = tokens.iter().zip(kinds)
+                .map(|(token, kind)| self.operand(&src, token, *kind))
+                .collect::<Result<_>>()?;
+            instructions.push(Instruction { opcode, operands });
+        }
+        Ok(Program { isa: self, instructions })
+    }
+}
+
+/// Instructions together with the instruction set they are written in. Displays as assembly again.
+#[derive(Debug, Clone)]
+pub struct Program {
+    pub isa: InstructionSet,
+    pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+    /// Cycles the instruction at `pc` takes.
+    pub fn cycles(&self, pc: usize) -> u32 {
+        self.isa.opcodes[self.instructions[pc].opcode].cycles
+    }
+
+    pub fn execute(&self, pc: usize, registers: &mut Registers) {
+        let instruction = &self.instructions[pc];
+        (self.isa.opcodes[instruction.opcode].execute)(registers, &instruction.operands)
+    }
+
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+}
+
+impl Display for Program {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for instruction in &self.instructions {
+            write!(f, "{}", self.isa.opcodes[instruction.opcode].mnemonic)?;
+            for operand in &instruction.operands {
+                match operand {
+                    Operand::Register(r) => write!(f, " {}", self.isa.registers[*r].0)?,
+                    Operand::Immediate(value) => write!(f, " {}", value)?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assemble() {
+        let program = InstructionSet::puzzle().assemble("noop\naddx 3\n\naddx -5 # back down\n").unwrap();
+        assert_eq!(program.instructions, vec![
+            Instruction { opcode: 0, operands: vec![] },
+            Instruction { opcode: 1, operands: vec![Operand::Immediate(3)] },
+            Instruction { opcode: 1, operands: vec![Operand::Immediate(-5)] },
+        ]);
+        assert_eq!(program.to_string(), "noop\naddx 3\naddx -5\n");
+        let text = "set y 3\nmul y y\naddx y\nsub x -2\n";
+        assert_eq!(InstructionSet::extended().assemble(text).unwrap().to_string(), text);
+    }
+
+    #[test]
+    fn test_assemble_errors() {
+        let error = |isa: InstructionSet, text: &str| {
+            isa.assemble(text).unwrap_err().to_string().lines().next().unwrap().to_string()
+        };
+        assert_eq!(error(InstructionSet::puzzle(), "noop\nadd x 1\n"), "line 2, column 1: Unknown instruction 'add'");
+        assert_eq!(error(InstructionSet::puzzle(), "addx\n"), "line 1, column 1: addx expects 1 operand, found 0");
+        assert_eq!(error(InstructionSet::puzzle(), "noop 1\n"), "line 1, column 1: noop expects 0 operands, found 1");
+        assert_eq!(error(InstructionSet::puzzle(), "addx 1O\n"), "line 1, column 6: Unknown register or invalid number '1O'");
+        assert_eq!(error(InstructionSet::extended(), "set 1 2\n"), "line 1, column 5: Expected a register instead of '1'");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,96 @@
+use crate::{Error, Result};
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+/// Glyphs are separated by one dark column.
+pub const GLYPH_PITCH: usize = GLYPH_WIDTH + 1;
+
+// the letters of the puzzles' 4x6 font, rows from top to bottom
+const FONT: &[(char, [&str; GLYPH_HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###.", ".#..", ".#..", ".#..", ".#..", "###."]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads the letters off a screen drawn with `#` for lit and any other character for dark pixels.
+pub fn recognize(screen: &str) -> Result<String> {
+    let rows: Vec<Vec<bool>> = screen.lines().map(|line| line.chars().map(|c| c == '#').collect()).collect();
+    if rows.len() != GLYPH_HEIGHT {
+        return Err(Error::invalid(format!("Expected {} rows of pixels, found {}", GLYPH_HEIGHT, rows.len())));
+    }
+    let width = rows[0].len();
+    if rows.iter().any(|row| row.len() != width) {
+        return Err(Error::invalid("The rows of the screen differ in length"));
+    }
+    (0..width.div_ceil(GLYPH_PITCH))
+        .map(|i| {
+            let glyph: Vec<String> = rows.iter()
+                .map(|row| (i * GLYPH_PITCH..i * GLYPH_PITCH + GLYPH_WIDTH)
+                    .map(|x| if row.get(x) == Some(&true) { '#' } else { '.' })
+                    .collect())
+                .collect();
+            FONT.iter()
+                .find(|(_, pattern)| pattern.iter().eq(glyph.iter()))
+                .map(|(letter, _)| *letter)
+                .ok_or_else(|| Error::invalid(format!("Unknown glyph {} at column {}:\n{}", i + 1, i * GLYPH_PITCH + 1,
+                                                      glyph.join("\n"))))
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // renders text in the font like the CRT would
+    fn draw(text: &str) -> String {
+        (0..GLYPH_HEIGHT)
+            .map(|row| text.chars()
+                .map(|c| FONT.iter().find(|(letter, _)| *letter == c).unwrap().1[row])
+                .collect::<Vec<_>>()
+                .join("."))
+            .map(|line| line + ".\n")
+            .collect()
+    }
+
+    #[test]
+    fn test_recognize() {
+        let screen = "\
+###..####..##..###..#..#.###..####.###..
+#..#....#.#..#.#..#.#.#..#..#.#....#..#.
+#..#...#..#....#..#.##...#..#.###..###..
+###...#...#.##.###..#.#..###..#....#..#.
+#....#....#..#.#....#.#..#....#....#..#.
+#....####..###.#....#..#.#....####.###..
+";
+        assert_eq!(recognize(screen).unwrap(), "PZGPKPEB");
+        let alphabet: String = FONT.iter().map(|(letter, _)| *letter).collect();
+        assert_eq!(recognize(&draw(&alphabet)).unwrap(), alphabet);
+        // the dark column after the last letter is optional
+        let trimmed: String = draw("HI").lines().map(|line| format!("{}\n", &line[..line.len() - 1])).collect();
+        assert_eq!(recognize(&trimmed).unwrap(), "HI");
+    }
+
+    #[test]
+    fn test_unknown_glyph() {
+        let screen = draw("CAB").replacen(".##.", "####", 1);
+        assert_eq!(recognize(&screen).unwrap_err().to_string(),
+                   "Unknown glyph 1 at column 1:\n####\n#..#\n#...\n#...\n#..#\n.##.");
+        assert!(recognize("#..#\n").is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,241 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use itertools::Itertools;
+use crate::{Error, Param, Params, Result, Solution, Source};
+
+pub mod expression;
+
+pub use expression::{Expr, Op};
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Monkey {
+    items : RefCell<VecDeque<u64>>,
+    operation: Expr,
+    test_divisor: u64,
+    test_true: usize,
+    test_false: usize,
+    activity: RefCell<u64>
+}
+
+// next line of the monkey's notes, without the expected prefix
+fn field<'a>(src: &Source, lines: &mut impl Iterator<Item=&'a str>, block: &'a str, prefix: &str) -> Result<&'a str> {
+    let line = lines.next()
+        .ok_or_else(|| src.error(&block[block.len()..], format!("Expected '{}'", prefix.trim())))?;
+    src.strip_prefix(line, prefix)
+}
+
+impl Monkey {
+    fn parse(src: &Source, s: &str) -> Result<Monkey> {
+        let mut lines = s.lines();
+        field(src, &mut lines, s, "Monkey ")?;
+        let items_list = field(src, &mut lines, s, "  Starting items:")?.trim_start();
+        let items: RefCell<VecDeque<u64>> = RefCell::new(items_list.split(", ")
+            .filter(|x| !x.is_empty())
+            .map(|x| src.parse(x))
+            .collect::<Result<_>>()?);
+        let operation = Expr::parse(src, field(src, &mut lines, s, "  Operation: new = ")?)?;
+        let divisor = field(src, &mut lines, s, "  Test: divisible by ")?;
+        let test_divisor : u64 = src.parse(divisor)?;
+        if test_divisor == 0 {
+            return Err(src.error(divisor, "Divisor must not be zero"));
+        }
+        let test_true = src.parse(field(src, &mut lines, s, "    If true: throw to monkey ")?)?;
+        let test_false = src.parse(field(src, &mut lines, s, "    If false: throw to monkey ")?)?;
+        Ok(Monkey{items, operation, test_divisor, test_true, test_false, activity: RefCell::new(0) })
+    }
+}
+
+fn prepare_input(s: String) -> Result<Vec<Monkey>> {
+    let src = Source::new(&s);
+    let monkeys: Vec<Monkey> = s.split("\n\n").map(|m| Monkey::parse(&src, m)).collect::<Result<_>>()?;
+    if let Some(monkey) = monkeys.iter().find(|m| m.test_true.max(m.test_false) >= monkeys.len()) {
+        return Err(Error::invalid(format!("Monkey throws to monkey {}, but there are only {} monkeys",
+                                          monkey.test_true.max(monkey.test_false), monkeys.len())));
+    }
+    Ok(monkeys)
+}
+
+fn part1(monkeys: Vec<Monkey>, rounds : u64) -> Result<u64> {
+    solver(monkeys, rounds, false)
+}
+
+fn part2(monkeys: Vec<Monkey>, rounds : u64) -> Result<u64> {
+    solver(monkeys,rounds,true)
+}
+
+// least common multiple of all divisors, `None` if it does not fit into a u64
+fn common_modulo(monkeys: &[Monkey]) -> Option<u64> {
+    monkeys.iter().try_fold(1u64, |lcm, monkey| {
+        let (mut a, mut b) = (lcm, monkey.test_divisor);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        (lcm / a).checked_mul(monkey.test_divisor)
+    })
+}
+
+fn solver(monkeys: Vec<Monkey>, rounds : u64, part2 : bool) -> Result<u64> {
+    // part2 keeps the worry levels small modulo a multiple of all divisors, but only if every operation allows
+    // that and the modulus fits, otherwise the exact levels are needed and may overflow
+    let modulo = common_modulo(&monkeys)
+        .filter(|_| part2 && monkeys.iter().all(|monkey| monkey.operation.is_modular()));
+    for _round in 0..rounds {
+        for (index, monkey) in monkeys.iter().enumerate() {
+            while !monkey.items.borrow().is_empty() {
+                let mut item = monkey.items.borrow_mut().pop_front().unwrap();
+                *monkey.activity.borrow_mut() += 1;
+                item = if let Some(modulo) = modulo {
+                    monkey.operation.eval_mod(item, modulo)
+                } else {
+                    let new = monkey.operation.eval(item)
+                        .map_err(|e| Error::invalid(format!("Monkey {} inspecting an item of worry level {}: {}",
+                                                            index, item, e)))?;
+                    if part2 { new } else { new / 3 }
+                };
+                if item % monkey.test_divisor == 0 {
+                    monkeys[monkey.test_true].items.borrow_mut().push_back(item);
+                } else {
+                    monkeys[monkey.test_false].items.borrow_mut().push_back(item);
+                }
+            }
+        }
+    }
+    Ok(monkeys
+        .iter()
+        .map(|monkey| *monkey.activity.borrow())
+        .sorted()
+        .rev()
+        .take(2)
+        .product())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Answer1 = Result<u64>;
+    type Answer2 = Result<u64>;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        Self::part1_with(input, &Params::new(Self::PARAMS))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        Self::part2_with(input, &Params::new(Self::PARAMS))
+    }
+
+    const PARAMS: &'static [Param] = &[
+        Param { name: "rounds1", default: 20, min: 0, max: u32::MAX as i64 },
+        Param { name: "rounds2", default: 10000, min: 0, max: u32::MAX as i64 },
+    ];
+
+    fn part1_with(input: &Self::Input, params: &Params) -> Self::Answer1 {
+        part1(input.clone(), params.get("rounds1") as u64)
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> Self::Answer2 {
+        part2(input.clone(), params.get("rounds2") as u64)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/11.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part1(input, 20).unwrap(), 10605);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/11.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part2(input, 10000).unwrap(), 2713310158);
+    }
+
+    #[test]
+    fn test_expressions() {
+        let data = load_input("input/11.test.txt").unwrap()
+            .replace("new = old * 19", "new = (old + 1) * 19 - 19")
+            .replace("new = old + 6", "new = (old + 9) - 3");
+        assert_eq!(part1(prepare_input(data.clone()).unwrap(), 20).unwrap(), 10605);
+        assert_eq!(part2(prepare_input(data).unwrap(), 10000).unwrap(), 2713310158);
+        let notes = "Monkey 0:\n  Starting items: 1\n  Operation: new = old ^ 2\n";
+        assert_eq!(prepare_input(notes.to_string()).unwrap_err().to_string(),
+                   "line 3, column 24: Unexpected '^' after the expression\n    Operation: new = old ^ 2\n                         ^");
+    }
+
+    #[test]
+    fn test_exact_worry_levels() {
+        let notes = "\
+Monkey 0:
+  Starting items: 7, 10
+  Operation: new = old / 2 + 5
+  Test: divisible by 3
+    If true: throw to monkey 1
+    If false: throw to monkey 2
+
+Monkey 1:
+  Starting items: 4
+  Operation: new = old * 3 + 1
+  Test: divisible by 2
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 9
+  Operation: new = old + 7
+  Test: divisible by 5
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+        let monkeys = prepare_input(notes.to_string()).unwrap();
+        // the division needs the exact worry levels, remainders modulo 30 would give 2448
+        assert_eq!(part2(monkeys.clone(), 20).unwrap(), 3038);
+        assert!(part2(monkeys, 100).unwrap_err().to_string().ends_with("'old * 3' overflows"));
+        let data = load_input("input/11.test.txt").unwrap().replace("new = old + 6", "new = old - 60");
+        assert_eq!(part1(prepare_input(data).unwrap(), 20).unwrap_err().to_string(),
+                   "Monkey 1 inspecting an item of worry level 54: 'old - 60' drops below zero");
+    }
+
+    #[test]
+    fn test_large_divisors() {
+        let notes = "\
+Monkey 0:
+  Starting items: 1
+  Operation: new = old + 1
+  Test: divisible by 1000000007
+    If true: throw to monkey 2
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items:
+  Operation: new = old + 1
+  Test: divisible by 1000000009
+    If true: throw to monkey 0
+    If false: throw to monkey 2
+
+Monkey 2:
+  Starting items:
+  Operation: new = old + 1
+  Test: divisible by 998244353
+    If true: throw to monkey 1
+    If false: throw to monkey 0";
+        let monkeys = prepare_input(notes.to_string()).unwrap();
+        assert_eq!(common_modulo(&monkeys), None);
+        // no modulus fits into a u64, so part 2 falls back to the exact worry levels
+        assert_eq!(part2(monkeys, 10).unwrap(), 100);
+        let mut monkeys = prepare_input(load_input("input/11.test.txt").unwrap()).unwrap();
+        monkeys.iter_mut().for_each(|monkey| monkey.test_divisor = 10000000);
+        assert_eq!(common_modulo(&monkeys), Some(10000000));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,250 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{Error, Result, Source};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Op {
+    fn precedence(self) -> u8 {
+        match self {
+            Op::Add | Op::Sub => 1,
+            Op::Mul | Op::Div => 2,
+        }
+    }
+
+    fn symbol(self) -> char {
+        match self {
+            Op::Add => '+',
+            Op::Sub => '-',
+            Op::Mul => '*',
+            Op::Div => '/',
+        }
+    }
+}
+
+/// Right hand side of a monkey's operation, e.g. `old * (old + 3)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Old,
+    Constant(u64),
+    Binary(Box<Expr>, Op, Box<Expr>),
+}
+
+// recursive descent over the remaining text, which stays a sub slice of the notes for error locations
+struct Parser<'a, 'b> {
+    src: &'b Source<'a>,
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a, '_> {
+    fn peek(&mut self) -> &'a str {
+        self.rest = self.rest.trim_start();
+        let len = match self.rest.chars().next() {
+            Some(c) if c.is_ascii_alphanumeric() => self.rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(self.rest.len()),
+            Some(c) => c.len_utf8(),
+            None => 0,
+        };
+        &self.rest[..len]
+    }
+
+    fn next(&mut self) -> &'a str {
+        let token = self.peek();
+        self.rest = &self.rest[token.len()..];
+        token
+    }
+
+    fn describe(token: &str) -> String {
+        if token.is_empty() { "the end".to_string() } else { format!("'{}'", token) }
+    }
+
+    fn binary(&mut self, precedence: u8) -> Result<Expr> {
+        let mut left = if precedence == 2 { self.factor()? } else { self.binary(precedence + 1)? };
+        loop {
+            let op = match self.peek() {
+                "+" => Op::Add,
+                "-" => Op::Sub,
+                "*" => Op::Mul,
+                "/" => Op::Div,
+                _ => return Ok(left),
+            };
+            if op.precedence() != precedence {
+                return Ok(left);
+            }
+            let token = self.next();
+            let right = if precedence == 2 { self.factor()? } else { self.binary(precedence + 1)? };
+            if op == Op::Div && right == Expr::Constant(0) {
+                return Err(self.src.error(token, "Division by zero"));
+            }
+            left = Expr::Binary(Box::new(left), op, Box::new(right));
+            // constant sub-expressions are evaluated right away, e.g. to catch a division by `(1 - 1)`
+            if let Expr::Binary(l, _, r) = &left {
+                if matches!((&**l, &**r), (Expr::Constant(_), Expr::Constant(_))) {
+                    left = Expr::Constant(left.eval(0).map_err(|e| self.src.error(token, e))?);
+                }
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Result<Expr> {
+        let token = self.next();
+        match token {
+            "old" => Ok(Expr::Old),
+            "(" => {
+                let inner = self.binary(1)?;
+                match self.next() {
+                    ")" => Ok(inner),
+                    token => Err(self.src.error(token, format!("Expected ')' instead of {}", Parser::describe(token)))),
+                }
+            }
+            token if token.starts_with(|c: char| c.is_ascii_digit()) => Ok(Expr::Constant(self.src.parse(token)?)),
+            token => Err(self.src.error(token, format!("Expected 'old', a number or '(' instead of {}",
+                                                       Parser::describe(token)))),
+        }
+    }
+}
+
+impl Expr {
+    /// Parses `text`, a sub slice of the notes behind `src`.
+    pub fn parse(src: &Source, text: &str) -> Result<Expr> {
+        let mut parser = Parser { src, rest: text };
+        let expr = parser.binary(1)?;
+        match parser.peek() {
+            "" => Ok(expr),
+            token => Err(src.error(token, format!("Unexpected '{}' after the expression", token))),
+        }
+    }
+
+    /// Exact value for the worry level `old`, errors name the part that overflows, goes below zero or divides by zero.
+    pub fn eval(&self, old: u64) -> Result<u64> {
+        match self {
+            Expr::Old => Ok(old),
+            Expr::Constant(value) => Ok(*value),
+            Expr::Binary(left, op, right) => {
+                let (left, right) = (left.eval(old)?, right.eval(old)?);
+                let (value, problem) = match op {
+                    Op::Add => (left.checked_add(right), "overflows"),
+                    Op::Sub => (left.checked_sub(right), "drops below zero"),
+                    Op::Mul => (left.checked_mul(right), "overflows"),
+                    Op::Div => (left.checked_div(right), "divides by zero"),
+                };
+                value.ok_or_else(|| Error::invalid(format!("'{}' {}", self, problem)))
+            }
+        }
+    }
+
+    // lower bound of the value for any worry level, `None` for divisions and subtractions that may go below zero
+    fn minimum(&self) -> Option<u64> {
+        match self {
+            Expr::Old => Some(0),
+            Expr::Constant(value) => Some(*value),
+            Expr::Binary(left, Op::Add, right) => Some(left.minimum()?.saturating_add(right.minimum()?)),
+            Expr::Binary(left, Op::Mul, right) => Some(left.minimum()?.saturating_mul(right.minimum()?)),
+            Expr::Binary(left, Op::Sub, right) => match **right {
+                Expr::Constant(value) => left.minimum()?.checked_sub(value),
+                _ => None,
+            },
+            Expr::Binary(_, Op::Div, _) => None,
+        }
+    }
+
+    /// Whether the value modulo a number follows from `old` modulo that number: the expression does not divide
+    /// and none of its subtractions can go below zero.
+    pub fn is_modular(&self) -> bool {
+        self.minimum().is_some()
+    }
+
+    /// Value modulo `modulus`, only for expressions that are [`Expr::is_modular`].
+    pub fn eval_mod(&self, old: u64, modulus: u64) -> u64 {
+        match self {
+            Expr::Old => old % modulus,
+            Expr::Constant(value) => value % modulus,
+            Expr::Binary(left, op, right) => {
+                let (left, right) = (left.eval_mod(old, modulus) as u128, right.eval_mod(old, modulus) as u128);
+                let modulus = modulus as u128;
+                (match op {
+                    Op::Add => (left + right) % modulus,
+                    Op::Sub => (left + modulus - right) % modulus,
+                    Op::Mul => left * right % modulus,
+                    Op::Div => panic!("Division can not be evaluated modulo {}", modulus),
+                }) as u64
+            }
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Old => write!(f, "old"),
+            Expr::Constant(value) => write!(f, "{}", value),
+            Expr::Binary(left, op, right) => {
+                // parentheses only where the precedence or the order of - and / needs them
+                let wrap = |expr: &Expr, right_side: bool| match expr {
+                    Expr::Binary(_, inner, _) => inner.precedence() < op.precedence()
+                        || (right_side && inner.precedence() == op.precedence() && matches!(op, Op::Sub | Op::Div)),
+                    _ => false,
+                };
+                let side = |expr: &Expr, right_side: bool| match wrap(expr, right_side) {
+                    true => format!("({})", expr),
+                    false => expr.to_string(),
+                };
+                write!(f, "{} {} {}", side(left, false), op.symbol(), side(right, true))
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Result<Expr> {
+        Expr::parse(&Source::new(text), text)
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("old * 19").unwrap(), Expr::Binary(Box::new(Expr::Old), Op::Mul, Box::new(Expr::Constant(19))));
+        assert_eq!(parse("old*old").unwrap().eval(7).unwrap(), 49);
+        let expr = parse("2 * (old + 3) - old / 2 - 1").unwrap();
+        assert_eq!(expr.eval(10).unwrap(), 20);
+        assert_eq!(expr.to_string(), "2 * (old + 3) - old / 2 - 1");
+        assert_eq!(parse("old - (old - 1)").unwrap().to_string(), "old - (old - 1)");
+        assert_eq!(parse("((old))").unwrap(), Expr::Old);
+        assert_eq!(parse("2 * 3 + old * (10 / 4)").unwrap().to_string(), "6 + old * 2");
+    }
+
+    #[test]
+    fn test_eval() {
+        let error = |text: &str, old: u64| parse(text).unwrap().eval(old).unwrap_err().to_string();
+        assert_eq!(error("old - 5", 3), "'old - 5' drops below zero");
+        assert_eq!(error("old / (old - old)", 3), "'old / (old - old)' divides by zero");
+        assert_eq!(error("old * old * old + 1", u64::MAX / 2), "'old * old' overflows");
+        let expr = parse("(old + 3) * (old + 4) - 7 + 3 * old").unwrap();
+        assert!(expr.is_modular());
+        assert!((0..100).all(|old| expr.eval_mod(old, 13) == expr.eval(old + 13).unwrap() % 13));
+        assert!(!parse("old / 2").unwrap().is_modular());
+        assert!(!parse("old * 3 - 7").unwrap().is_modular());
+        assert!(!parse("old * old - old").unwrap().is_modular());
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |text: &str| parse(text).unwrap_err().to_string();
+        assert_eq!(error("old ^ 2"), "line 1, column 5: Unexpected '^' after the expression\n  old ^ 2\n      ^");
+        assert!(error("old +").starts_with("line 1, column 6: Expected 'old', a number or '(' instead of the end"));
+        assert!(error("(old + 1").starts_with("line 1, column 9: Expected ')' instead of the end"));
+        assert!(error("new * 2").starts_with("line 1, column 1: Expected 'old', a number or '(' instead of 'new'"));
+        assert!(error("old / 0").starts_with("line 1, column 5: Division by zero"));
+        assert!(error("old / (1 - 1)").starts_with("line 1, column 5: Division by zero"));
+        assert!(error("(2 - 3) * old").starts_with("line 1, column 4: '2 - 3' drops below zero"));
+        assert!(error("old + 99999999999999999999").starts_with("line 1, column 7: Unable to parse"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,232 @@
+use itertools::Itertools;
+
+use crate::{Direction, Error, Grid, Point, Result, Solution};
+use crate::search::{bfs_indexed, distances_indexed};
+
+fn prepare_input(s: String) -> Result<Grid<char>> {
+    let map = Grid::parse(&s, |c| match c {
+        'a'..='z' | 'S' | 'E' => Ok(c),
+        c => Err(format!("Invalid height '{}'", c)),
+    })?;
+    for letter in ['S', 'E'] {
+        if map.cells().filter(|(_, c)| **c == letter).count() != 1 {
+            return Err(Error::invalid(format!("Expected exactly one '{}' in the heightmap", letter)));
+        }
+    }
+    Ok(map)
+}
+
+type NodesConnected = fn(Point, Point, &Grid<char>) -> bool;
+
+// S has elevation a and E elevation z
+fn elevation(letter: char) -> u32 {
+    match letter {
+        'S' => 'a' as u32,
+        'E' => 'z' as u32,
+        letter => letter as u32,
+    }
+}
+
+// climbing at most one level up, descending any number of levels
+fn nodes_connected(node1: Point, node2: Point, map: &Grid<char>) -> bool {
+    elevation(map[node2]) <= elevation(map[node1]) + 1
+}
+
+// the same steps walked backwards, for searching from E
+fn nodes_connected_inverse(node1: Point, node2: Point, map: &Grid<char>) -> bool {
+    nodes_connected(node2, node1, map)
+}
+
+// cells reachable in one step from cell `node` according to `connected`, by row-major index
+fn neighbours(map: &Grid<char>, node: usize, connected: NodesConnected) -> impl Iterator<Item=usize> + '_ {
+    let node = map.point_at(node);
+    map.neighbours4(node)
+        .filter(move |(neighbour, _)| connected(node, *neighbour, map))
+        .map(|(neighbour, _)| map.index_of(neighbour).unwrap())
+}
+
+// shortest route from the square marked `from` to the closest square matching `goal`
+fn search(map: &Grid<char>, from: char, connected: NodesConnected, goal: impl Fn(char) -> bool) -> Option<Vec<Point>> {
+    let start = map.index_of(map.find(|letter| *letter == from).unwrap()).unwrap();
+    bfs_indexed(map.width() * map.height(), start, |node| neighbours(map, node, connected), |node| goal(map[map.point_at(node)]))
+        .map(|found| found.path.into_iter().map(|node| map.point_at(node)).collect())
+}
+
+/// Shortest route from S to E, both included, `None` if E can't be reached.
+pub fn climb(map: &Grid<char>) -> Option<Vec<Point>> {
+    search(map, 'S', nodes_connected, |letter| letter == 'E')
+}
+
+/// Shortest route from any square of elevation a to E, both included, `None` if E can't be reached.
+pub fn hike(map: &Grid<char>) -> Option<Vec<Point>> {
+    // search backwards from E to the closest square of elevation a
+    let mut path = search(map, 'E', nodes_connected_inverse, |letter| matches!(letter, 'a' | 'S'))?;
+    path.reverse();
+    Some(path)
+}
+
+/// Number of steps from every square to E, `None` where E can't be reached from.
+pub fn distance_field(map: &Grid<char>) -> Grid<Option<u32>> {
+    let end = map.index_of(map.find(|letter| *letter == 'E').unwrap()).unwrap();
+    let distances = distances_indexed(map.width() * map.height(), end, |node| neighbours(map, node, nodes_connected_inverse));
+    let mut field = Grid::new(map.width(), map.height(), None);
+    for (node, distance) in distances.into_iter().enumerate() {
+        field.set(map.point_at(node), distance);
+    }
+    field
+}
+
+/// The `k` squares matching `predicate` closest to E, ordered by distance.
+pub fn best_starts(map: &Grid<char>, field: &Grid<Option<u32>>, predicate: impl Fn(char) -> bool, k: usize)
+                   -> Vec<(Point, u32)> {
+    map.cells()
+        .filter(|(_, letter)| predicate(**letter))
+        .filter_map(|(pos, _)| field[pos].map(|distance| (pos, distance)))
+        .sorted_by_key(|(pos, distance)| (*distance, pos.y, pos.x))
+        .take(k)
+        .collect()
+}
+
+/// Shortest distance to E from any square matching `predicate`.
+pub fn shortest_from(map: &Grid<char>, field: &Grid<Option<u32>>, predicate: impl Fn(char) -> bool) -> Option<u32> {
+    best_starts(map, field, predicate, 1).first().map(|(_, distance)| *distance)
+}
+
+/// Plain PGM image of the distance field, squares close to E are bright and unreachable ones black.
+pub fn heatmap(field: &Grid<Option<u32>>) -> String {
+    let max = field.cells().filter_map(|(_, distance)| *distance).max().unwrap_or(0).max(1);
+    let mut image = format!("P2\n{} {}\n255\n", field.width(), field.height());
+    for y in 0..field.height() as i32 {
+        let row: Vec<String> = field.row(y)
+            .map(|(_, distance)| distance.map_or(0, |d| 255 - d * 254 / max).to_string())
+            .collect();
+        image.push_str(&row.join(" "));
+        image.push('\n');
+    }
+    image
+}
+
+/// Draws a route like the puzzle's illustration: arrows point to the next square, the end is marked with E.
+pub fn render_path(map: &Grid<char>, path: &[Point]) -> String {
+    let mut arrows = Grid::new(map.width(), map.height(), '.');
+    for step in path.windows(2) {
+        let arrow = match Direction::ALL.iter().find(|d| step[0] + d.offset() == step[1]) {
+            Some(Direction::Up) => '^',
+            Some(Direction::Right) => '>',
+            Some(Direction::Down) => 'v',
+            Some(Direction::Left) => '<',
+            None => panic!("{} and {} are not adjacent", step[0], step[1]),
+        };
+        arrows.set(step[0], arrow);
+    }
+    if let Some(end) = path.last() {
+        arrows.set(*end, 'E');
+    }
+    arrows.to_string()
+}
+
+fn part1(map: &Grid<char>) -> Result<usize> {
+    climb(map).map(|path| path.len() - 1).ok_or_else(|| Error::invalid("No path from S to E"))
+}
+
+fn part2(map: &Grid<char>) -> Result<usize> {
+    hike(map).map(|path| path.len() - 1).ok_or_else(|| Error::invalid("No path from any square of elevation a to E"))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+    type Answer1 = Result<usize>;
+    type Answer2 = Result<usize>;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/12.test.txt").unwrap();
+        let map = prepare_input(data).unwrap();
+        assert_eq!(part1(&map).unwrap(), 31);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/12.test.txt").unwrap();
+        let map = prepare_input(data).unwrap();
+        assert_eq!(part2(&map).unwrap(), 29);
+    }
+
+    #[test]
+    fn test_render_path() {
+        let data = load_input("input/12.test.txt").unwrap();
+        let map = prepare_input(data).unwrap();
+        let path = climb(&map).unwrap();
+        assert_eq!((map[path[0]], map[path[31]]), ('S', 'E'));
+        assert_eq!(render_path(&map, &path), ">>vv<<<<\n..vvv<<^\n..vv>E^^\n..v>>>^^\n..>>>>>^\n");
+        let path = hike(&map).unwrap();
+        assert_eq!((map[path[0]], path.len()), ('a', 30));
+        assert_eq!(render_path(&map, &path), "...v<<<<\n...vv<<^\n...v>E^^\n.>v>>>^^\n>^>>>>>^\n");
+    }
+
+    #[test]
+    fn test_distance_field() {
+        let data = load_input("input/12.test.txt").unwrap();
+        let map = prepare_input(data).unwrap();
+        let field = distance_field(&map);
+        assert_eq!(field[map.find(|letter| *letter == 'S').unwrap()], Some(31));
+        assert_eq!(shortest_from(&map, &field, |letter| matches!(letter, 'a' | 'S')), Some(29));
+        assert_eq!(shortest_from(&map, &field, |letter| letter == 'q'), Some(12));
+        assert_eq!(best_starts(&map, &field, |letter| letter == 'a', 3),
+                   vec![(Point::new(0, 4), 29), (Point::new(1, 0), 30), (Point::new(0, 1), 30)]);
+        let image = heatmap(&field);
+        assert!(image.starts_with("P2\n8 5\n255\n"));
+        assert_eq!(image.lines().nth(5).unwrap().split(' ').nth(5), Some("255"));
+    }
+
+    #[test]
+    fn test_large_heightmap() {
+        // columns rise from a to z, then stay at z, S is top left and E bottom right
+        let (width, height) = (300, 200);
+        let mut text = String::new();
+        for y in 0..height {
+            for x in 0..width {
+                text.push(match (x, y) {
+                    (0, 0) => 'S',
+                    (x, y) if x == width - 1 && y == height - 1 => 'E',
+                    (x, _) => (b'a' + x.min(25) as u8) as char,
+                });
+            }
+            text.push('\n');
+        }
+        let map = prepare_input(text).unwrap();
+        assert_eq!(part1(&map).unwrap(), width + height - 2);
+        assert_eq!(part2(&map).unwrap(), width - 1);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let map = prepare_input("Sazzz\naaazE".to_string()).unwrap();
+        assert_eq!(climb(&map), None);
+        assert_eq!(part1(&map).unwrap_err().to_string(), "No path from S to E");
+        assert_eq!(part2(&map).unwrap_err().to_string(), "No path from any square of elevation a to E");
+        assert_eq!(distance_field(&map)[Point::new(0, 0)], None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,145 @@
+use std::cmp::{min, Ordering};
+use std::collections::VecDeque;
+use crate::{Result, Solution, Source};
+use self::Entry::{List, Number};
+
+use serde_json::Value;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Entry {
+    Number(i32),
+    List{l: VecDeque<Entry>}
+}
+
+fn parse(src: &Source, line: &str, v : Value) -> Result<Entry> {
+    match v {
+        Value::Number(n) => {
+            n.as_i64()
+                .and_then(|n| i32::try_from(n).ok())
+                .map(Number)
+                .ok_or_else(|| src.error(line, format!("Invalid number {}", n)))
+        }
+        Value::Array(arr) => {
+            Ok(List {l: arr
+                .into_iter()
+                .map(|m| parse(src, line, m))
+                .collect::<Result<VecDeque<Entry>>>()?
+            })
+        }
+        v => Err(src.error(line, format!("Expected a list or a number, found {}", v)))
+    }
+}
+
+fn parse_packet(src: &Source, line: &str) -> Result<Entry> {
+    let value = serde_json::from_str(line).map_err(|e| {
+        let column = (e.column().max(1) - 1).min(line.len());
+        src.error(line.get(column..).unwrap_or(line), e)
+    })?;
+    parse(src, line, value)
+}
+
+fn prepare_input(s: String) -> Result<Vec<(Entry, Entry)>> {
+    let src = Source::new(&s);
+    let pairs : Vec<(&str, &str)> = s.split("\n\n").map(|pair| src.split_once(pair, "\n")).collect::<Result<_>>()?;
+    let mut pairs_entry: Vec<(Entry, Entry)> = vec![];
+    for (p0,p1) in pairs {
+        pairs_entry.push((parse_packet(&src, p0)?, parse_packet(&src, p1)?))
+    }
+    Ok(pairs_entry)
+}
+
+fn isrightorder(entry0 : Entry, entry1 : Entry) -> Option<bool> {
+    match (entry0, entry1) {
+        (Number(n1), Number(n2)) if n1 < n2 => { Some(true)}
+        (Number(n1), Number(n2)) if n1 == n2 => {None}
+        (Number(n1), Number(n2)) if n1 > n2 => { Some(false)}
+        (List {l:l1 }, List {l:l2 }) if l1.is_empty() && !l2.is_empty() => { Some(true)}
+        (List {l:l1 }, List {l:l2 }) if !l1.is_empty() && l2.is_empty() => { Some(false)}
+        (List {l: mut l1 }, List {l: mut l2 }) => {
+            let minlen = min(l1.len(), l2.len());
+            for _ in 0..minlen {
+                let elem1 = l1.pop_front().unwrap();
+                let elem2 = l2.pop_front().unwrap();
+                if let Some(cmp_result) = isrightorder(elem1, elem2) {return Some(cmp_result);}
+            }
+            if !(l1.is_empty() && l2.is_empty()) {isrightorder(List {l:l1}, List {l:l2})}
+            else { None }
+        }
+        (List {l:l1 }, Number(n2)) => { isrightorder(List {l:l1}, List {l: VecDeque::from(vec![Number(n2)])})}
+        (Number(n1), List {l:l2}) => { isrightorder(List {l: VecDeque::from(vec![Number(n1)])}, List {l: l2})}
+        (e0, e1) => {unreachable!("Unreachable {:?} {:?}", e0, e1)}
+    }
+}
+
+
+fn part1(pairs: &[(Entry, Entry)]) -> i32 {
+    pairs.iter()
+        .enumerate()
+        .filter(|(_, pair)| isrightorder(pair.0.clone(), pair.1.clone()) == Some(true))
+        .fold(0,| acc, (idx,_)| acc + idx +1)
+    as i32
+}
+
+fn part2(pairs: &[(Entry, Entry)]) -> i32 {
+    let divider = [List {l: VecDeque::from([List {l: VecDeque::from([Number(2)])}])},
+        List {l: VecDeque::from([List {l: VecDeque::from([Number(6)])}])}];
+    let mut flattened : Vec<_> = pairs.iter().flat_map(|(e1,e2)|vec![e1,e2]).collect();
+    for entry in divider.iter() {flattened.push(entry)}
+
+    flattened.sort_by(|e0, e1| match isrightorder((*e0).clone(), (*e1).clone()) {
+        Some(true) => Ordering::Less,
+        Some(false) => Ordering::Greater,
+        None => Ordering::Equal,
+    });
+
+    flattened.iter().enumerate().filter(|(_ , entry)| divider.contains(entry)).map(|(idx, _)| idx as i32 +1 ).product()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Entry, Entry)>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/13.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part1(&input), 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/13.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part2(&input), 140);
+    }
+
+    #[test]
+    fn test_equal_packets() {
+        let input = prepare_input("[1]\n[1]\n\n[1]\n[2]".to_string()).unwrap();
+        assert_eq!(part1(&input), 2);
+        assert_eq!(part2(&input), 30);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,155 @@
+use std::fmt::{Display, Formatter};
+use itertools::Itertools;
+use crate::{Error, Grid, Point, Result, Solution, Source};
+use crate::geometry::Direction8;
+use crate::grid::Sparse;
+use self::Tile::{Rock, Sand};
+
+#[derive(Debug, Clone)]
+pub enum Tile {
+    Sand,
+    Rock,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self { Sand => 'o', Rock => '#' })
+    }
+}
+
+type Cave = Grid<Tile, Sparse<Tile>>;
+
+fn parse_coords(src: &Source, s: &str) -> Result<Point> {
+    let (x, y) = src.split_once(s, ",")?;
+    Ok(Point::new(src.parse(x)?, src.parse(y)?))
+}
+
+fn prepare_input(s: String) -> Result<Cave> {
+    let src = Source::new(&s);
+    let mut map = Grid::sparse();
+    for line in s.lines() {
+        for (start, end) in line.split(" -> ").tuple_windows::<(_, _)>() {
+            let start_coords = parse_coords(&src, start)?;
+            let end_coords = parse_coords(&src, end)?;
+            // sand falls down from the source, rock at or above it could never stop it
+            for (text, coords) in [(start, start_coords), (end, end_coords)] {
+                if coords.y <= SOURCE.y {
+                    return Err(src.error(text, format!("Rock has to lie below the sand source at y = {}", SOURCE.y)));
+                }
+            }
+            if start_coords.x != end_coords.x && start_coords.y != end_coords.y {
+                return Err(src.error(start, "Rock paths have to be horizontal or vertical"));
+            }
+            let step = (end_coords - start_coords).signum();
+            let mut rock = start_coords;
+            map.insert(rock, Rock);
+            while rock != end_coords {
+                rock += step;
+                map.insert(rock, Rock);
+            }
+        }
+    }
+    if map.is_empty() {
+        return Err(Error::invalid("The cave contains no rock"));
+    }
+    Ok(map)
+}
+
+// sand tries to move down, then diagonal left down, then diagonal right down
+const FALLING: [Direction8; 3] = [Direction8::S, Direction8::SW, Direction8::SE];
+const SOURCE: Point = Point::new(500, 0);
+
+fn solver(mut map: Cave, part2: bool) -> i32 {
+    let mut sand_counter = 0;
+    let lowest = map.bounds().max.y;
+    loop {
+        let mut sand = SOURCE;
+        sand_counter += 1;
+        while part2 || sand.y < lowest { // if we are in part 1 then loop until sand.y < lowest, else loop indefinitely
+            // sand settles on the floor
+            if part2 && sand.y == lowest + 1 {
+                map.insert(sand, Sand);
+                break;
+            }
+            match FALLING.iter().map(|d| sand + d.offset()).find(|next| map.get(*next).is_none()) {
+                Some(next) => sand = next,
+                // sand settles
+                None => {
+                    map.insert(sand, Sand);
+                    break;
+                }
+            }
+        }
+        if !part2 && sand.y == lowest {
+            sand_counter -= 1; // the last sand fell off the pyramid
+            break;
+        }
+        if part2 && sand == SOURCE {
+            break;
+        }
+    }
+    sand_counter
+}
+
+fn part1(map: Cave) -> i32 {
+    solver(map, false)
+}
+
+fn part2(map: Cave) -> i32 {
+    solver(map, true)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Cave;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input.clone())
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input.clone())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/14.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        println!("{:?}", input);
+        assert_eq!(part1(input), 24);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/14.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part2(input), 93);
+    }
+    #[test]
+    fn test_display() {
+        let data = load_input("input/14.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(input.to_string(), "....#...##\n....#...#.\n..###...#.\n........#.\n........#.\n#########.\n");
+    }
+
+    #[test]
+    fn test_rock_above_source() {
+        let error = prepare_input("498,4 -> 498,6\n498,-5 -> 502,-5".to_string()).unwrap_err();
+        assert!(error.to_string().starts_with("line 2, column 1: Rock has to lie below the sand source at y = 0"));
+    }
+}
\ No newline at end of file
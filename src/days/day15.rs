@@ -0,0 +1,169 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+
+use crate::{Error, Param, Params, Point, Result, Solution, Source};
+
+// sensor position -> distance to its closest beacon
+type Sensors = HashMap<Point, i32>;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Range {
+    start: i32,
+    end: i32,
+}
+
+impl Range {
+    // only consider overlapping from one side
+    fn isoverlapping(&self, range: &Range) -> bool {
+        self.end <= range.end && self.end >= range.start
+    }
+}
+
+// x=2, y=18
+fn parse_position(src: &Source, s: &str) -> Result<Point> {
+    let (x, y) = src.split_once(s, ", ")?;
+    Ok(Point::new(src.parse(src.strip_prefix(x, "x=")?)?, src.parse(src.strip_prefix(y, "y=")?)?))
+}
+
+fn prepare_input(s: String) -> Result<(Sensors, HashSet<Point>)> {
+    let src = Source::new(&s);
+    let mut map = HashMap::new();
+    let mut beacons: HashSet<Point> = HashSet::new();
+    for line in s.lines() {
+        let (sensor, beacon) = src.split_once(src.strip_prefix(line, "Sensor at ")?, ": closest beacon is at ")?;
+        let sensor = parse_position(&src, sensor)?;
+        let beacon = parse_position(&src, beacon)?;
+        map.insert(sensor, sensor.manhattan(beacon));
+        beacons.insert(beacon);
+    }
+    Ok((map, beacons))
+}
+
+// how far the range of a sensor reaches along `row` to either side, computed in i64 as rows may be far away
+fn reach(sensor: Point, d: i32, row: i32) -> Option<i32> {
+    let rest = d as i64 - (sensor.y as i64 - row as i64).abs();
+    (rest >= 0).then_some(rest as i32)
+}
+
+fn part1(map: &Sensors, beacons: &HashSet<Point>, row: i32) -> i32 {
+    let mut checked = HashSet::new();
+    for (sensor, d) in map {
+        let Some(restdistance) = reach(*sensor, *d, row) else { continue };
+        let end1 = sensor.x + restdistance;
+        let end2 = sensor.x - restdistance;
+        for pos in end2..=end1 {
+            checked.insert(pos);
+        }
+    }
+    let beacon_count = beacons.iter().filter(|beacon| beacon.y == row).count();
+    (checked.len() - beacon_count) as i32
+}
+
+// leftmost position in 0..=limit that none of the ranges covers
+fn first_gap(ranges: &[Range], limit: i32) -> Option<i32> {
+    let mut x = 0;
+    for range in ranges.iter().sorted_by_key(|r| r.start) {
+        if range.start > x { break; }
+        x = x.max(range.end + 1);
+    }
+    (x <= limit).then_some(x)
+}
+
+fn part2(map: &Sensors, limit: i32) -> Result<u64> {
+    for y in 0..=limit {
+        let mut checked = Vec::new();
+        for (sensor, d) in map {
+            let Some(restdistance) = reach(*sensor, *d, y) else { continue };
+            let end1 = sensor.x + restdistance;
+            let end2 = sensor.x - restdistance;
+            checked.push(Range { start: end2, end: end1 })
+        }
+        // connect ranges
+        let mut update_range = true;
+        while checked.len() > 1 && update_range {
+            update_range = false;
+            let mut newchecked = checked.clone();
+            for i in 0..checked.len() {
+                for j in 0..checked.len() {
+                    if i == j { continue; }
+                    if checked[i].isoverlapping(&checked[j]) {
+                        let rangeconnected = Range { start: checked[i].start.min(checked[j].start), end: checked[j].end.max(checked[i].end) };
+                        newchecked.retain(|x| *x != checked[i] && *x != checked[j]);
+                        newchecked.push(rangeconnected);
+                        update_range = true;
+                        break;
+                    }
+                }
+                if update_range { break; }
+            }
+            if update_range { checked = newchecked; }
+        }
+
+        // we assume there is exactly one possible location for the distress beacon
+        if let Some(x) = first_gap(&checked, limit) {
+            return Ok(x as u64 * 4000000 + y as u64);
+        }
+    }
+    Err(Error::invalid(format!("No position for the distress beacon within 0..={}", limit)))
+}
+
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Sensors, HashSet<Point>);
+    type Answer1 = i32;
+    type Answer2 = Result<u64>;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        Self::part1_with(input, &Params::new(Self::PARAMS))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        Self::part2_with(input, &Params::new(Self::PARAMS))
+    }
+
+    const PARAMS: &'static [Param] = &[
+        Param { name: "row", default: 2000000, min: i32::MIN as i64, max: i32::MAX as i64 },
+        Param { name: "limit", default: 4000000, min: 0, max: i32::MAX as i64 },
+    ];
+
+    fn part1_with(input: &Self::Input, params: &Params) -> Self::Answer1 {
+        part1(&input.0, &input.1, params.get("row") as i32)
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> Self::Answer2 {
+        part2(&input.0, params.get("limit") as i32)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/15.test.txt").unwrap();
+        let (map, beacons) = prepare_input(data).unwrap();
+        assert_eq!(part1(&map, &beacons, 10), 26);
+        assert_eq!(part1(&map, &beacons, i32::MIN), 0);
+        assert_eq!(part1(&map, &beacons, i32::MAX), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/15.test.txt").unwrap();
+        let (map, _beacons) = prepare_input(data).unwrap();
+        assert_eq!(part2(&map, 20).unwrap(), 56000011);
+        assert_eq!(part2(&map, 3).unwrap_err().to_string(), "No position for the distress beacon within 0..=3");
+        assert!(part2(&map, 0).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use crate::{Result, Solution, Source};
+
+fn prepare_input(s: String) -> Result<Vec<i32>> {
+    let src = Source::new(&s);
+    s.lines()
+        .map(|x| src.parse(x))
+        .collect()
+}
+
+fn part1(_input: &[i32]) -> i32 {
+    0
+}
+
+fn part2(_input: &[i32]) -> i32 {
+    0
+}
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: String) -> Result<Self::Input> {
+        prepare_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let data = load_input("input/xx.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part1(&input), 0); // expected answer of the example
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = load_input("input/xx.test.txt").unwrap();
+        let input = prepare_input(data).unwrap();
+        assert_eq!(part2(&input), 0); // expected answer of the example
+    }
+}
\ No newline at end of file
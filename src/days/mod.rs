@@ -0,0 +1,76 @@
+use crate::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod dayxx_template;
+
+/// All solved days, ordered by day number.
+pub static DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1),
+    Day::new::<day02::Day02>(2),
+    Day::new::<day03::Day03>(3),
+    Day::new::<day04::Day04>(4),
+    Day::new::<day05::Day05>(5),
+    Day::new::<day06::Day06>(6),
+    Day::new::<day07::Day07>(7),
+    Day::new::<day08::Day08>(8),
+    Day::new::<day09::Day09>(9),
+    Day::new::<day10::Day10>(10),
+    Day::new::<day11::Day11>(11),
+    Day::new::<day12::Day12>(12),
+    Day::new::<day13::Day13>(13),
+    Day::new::<day14::Day14>(14),
+    Day::new::<day15::Day15>(15),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::load_input;
+
+    use super::*;
+
+    #[test]
+    fn test_registry_order() {
+        assert!(DAYS.windows(2).all(|days| days[0].number < days[1].number));
+        assert!((1..=15).all(|number| get(number).is_some()));
+    }
+
+    #[test]
+    fn test_registry_dispatch() {
+        let day = get(5).unwrap();
+        let input = day.parse(load_input("input/05.test.txt").unwrap()).unwrap();
+        assert_eq!(day.part1(&input).unwrap(), "CMZ");
+        assert_eq!(day.part2(&input).unwrap(), "MCD");
+        assert!(get(26).is_none());
+    }
+
+    #[test]
+    fn test_registry_params() {
+        let day = get(15).unwrap();
+        let input = day.load("input/15.test.txt").unwrap();
+        let mut params = day.params();
+        params.assign("row=10").unwrap();
+        params.assign("limit=20").unwrap();
+        assert_eq!(day.part1_with(&input, &params).unwrap(), "26");
+        assert_eq!(day.part2_with(&input, &params).unwrap(), "56000011");
+        assert!(get(1).unwrap().params().assign("row=10").is_err());
+    }
+}
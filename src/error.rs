@@ -0,0 +1,138 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum Error {
+    /// A file could not be read or written.
+    Io { path: PathBuf, source: io::Error },
+    /// The input is malformed at the given line and column (both starting at 1).
+    Parse { file: Option<PathBuf>, line: usize, column: usize, text: String, message: String },
+    /// The input is well-formed but violates an assumption of the puzzle.
+    Invalid { file: Option<PathBuf>, message: String },
+    /// The puzzle input of a day could not be downloaded.
+    Fetch { day: u8, message: String },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn invalid(message: impl Display) -> Error {
+        Error::Invalid { file: None, message: message.to_string() }
+    }
+
+    /// Attaches the file the input was read from, so the message points into it.
+    pub fn in_file(self, path: impl AsRef<Path>) -> Error {
+        let path = Some(path.as_ref().to_path_buf());
+        match self {
+            Error::Parse { line, column, text, message, .. } => Error::Parse { file: path, line, column, text, message },
+            Error::Invalid { message, .. } => Error::Invalid { file: path, message },
+            error => error,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "Unable to access {}: {}", path.display(), source),
+            Error::Parse { file, line, column, text, message } => {
+                match file {
+                    Some(file) => write!(f, "{}:{}:{}: {}", file.display(), line, column, message)?,
+                    None => write!(f, "line {}, column {}: {}", line, column, message)?,
+                }
+                write!(f, "\n  {}\n  {}^", text, " ".repeat(column - 1))
+            }
+            Error::Invalid { file: Some(file), message } => write!(f, "{}: {}", file.display(), message),
+            Error::Invalid { file: None, message } => write!(f, "{}", message),
+            Error::Fetch { day, message } => write!(f, "Unable to fetch the input of day {}: {}", day, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Puzzle input that maps sub slices back to line and column for error messages.
+pub struct Source<'a>(&'a str);
+
+impl<'a> Source<'a> {
+    pub fn new(text: &'a str) -> Source<'a> {
+        Source(text)
+    }
+
+    /// Parse error pointing at `at`, which has to be a sub slice of the input.
+    pub fn error(&self, at: &str, message: impl Display) -> Error {
+        let offset = (at.as_ptr() as usize).wrapping_sub(self.0.as_ptr() as usize);
+        let offset = if offset <= self.0.len() && self.0.is_char_boundary(offset) { offset } else { 0 };
+        let line_start = self.0[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.0[offset..].find('\n').map_or(self.0.len(), |i| offset + i);
+        Error::Parse {
+            file: None,
+            line: self.0[..offset].matches('\n').count() + 1,
+            column: self.0[line_start..offset].chars().count() + 1,
+            text: self.0[line_start..line_end].to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn parse<T: FromStr>(&self, at: &str) -> Result<T>
+        where T::Err: Display {
+        at.parse().map_err(|e| self.error(at, format!("Unable to parse '{}': {}", at, e)))
+    }
+
+    pub fn split_once<'b>(&self, at: &'b str, delimiter: &str) -> Result<(&'b str, &'b str)> {
+        at.split_once(delimiter)
+            .ok_or_else(|| self.error(at, format!("Expected '{}' in '{}'", delimiter, at)))
+    }
+
+    pub fn strip_prefix<'b>(&self, at: &'b str, prefix: &str) -> Result<&'b str> {
+        at.strip_prefix(prefix)
+            .ok_or_else(|| self.error(at, format!("Expected '{}'", prefix)))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_location() {
+        let text = "1 2\n3 x4\n";
+        let src = Source::new(text);
+        let at = &text[6..8];
+        match src.error(at, "bad") {
+            Error::Parse { line, column, text, .. } => {
+                assert_eq!((line, column), (2, 3));
+                assert_eq!(text, "3 x4");
+            }
+            e => panic!("Unexpected error {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_error_display() {
+        let text = "12\n1x\n";
+        let src = Source::new(text);
+        let error = src.parse::<i32>(&text[3..5]).unwrap_err().in_file("input/01.txt");
+        assert_eq!(error.to_string(),
+                   "input/01.txt:2:1: Unable to parse '1x': invalid digit found in string\n  1x\n  ^");
+    }
+
+    #[test]
+    fn test_prefix_and_split() {
+        let text = "move 1 from 2";
+        let src = Source::new(text);
+        assert_eq!(src.strip_prefix(text, "move ").unwrap(), "1 from 2");
+        assert_eq!(src.split_once(text, " from ").unwrap(), ("move 1", "2"));
+        assert!(src.split_once(text, " to ").is_err());
+    }
+}
@@ -0,0 +1,217 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Point or vector on the integer plane. `y` grows downwards like the lines of the input,
+/// so `Direction::Up` is `(0, -1)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// `Point` used as a difference of two points.
+pub type Vec2 = Point;
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Point) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Component-wise sign, i.e. a single step towards the direction of the vector.
+    pub fn signum(self) -> Vec2 {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Quarter turn around the origin, counterclockwise as seen on screen.
+    pub fn rotate_left(self) -> Point {
+        Point::new(self.y, -self.x)
+    }
+
+    /// Quarter turn around the origin, clockwise as seen on screen.
+    pub fn rotate_right(self) -> Point {
+        Point::new(-self.y, self.x)
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item=Point> {
+        Direction::ALL.into_iter().map(move |direction| self + direction.offset())
+    }
+
+    /// Orthogonal and diagonal neighbours, clockwise starting with up.
+    pub fn neighbours8(self) -> impl Iterator<Item=Point> {
+        Direction8::ALL.into_iter().map(move |direction| self + direction.offset())
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Point {
+        Point::new(x, y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i32) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction { Up, Right, Down, Left }
+
+impl Direction {
+    /// Clockwise starting with up.
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// Directions including the diagonals, named after the compass with north being up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 { N, NE, E, SE, S, SW, W, NW }
+
+impl Direction8 {
+    /// Clockwise starting with north.
+    pub const ALL: [Direction8; 8] = [Direction8::N, Direction8::NE, Direction8::E, Direction8::SE,
+        Direction8::S, Direction8::SW, Direction8::W, Direction8::NW];
+
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction8::N => Point::new(0, -1),
+            Direction8::NE => Point::new(1, -1),
+            Direction8::E => Point::new(1, 0),
+            Direction8::SE => Point::new(1, 1),
+            Direction8::S => Point::new(0, 1),
+            Direction8::SW => Point::new(-1, 1),
+            Direction8::W => Point::new(-1, 0),
+            Direction8::NW => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let (a, b) = (Point::new(1, 2), Point::new(4, -2));
+        assert_eq!(a + b, Point::new(5, 0));
+        assert_eq!(a - b, Point::new(-3, 4));
+        assert_eq!(-a * 3, Point::new(-3, -6));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+        assert_eq!((b - a).signum(), Point::new(1, -1));
+        assert_eq!(Point::from((7, 8)).to_string(), "(7, 8)");
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point::new(1, 2), Point::new(4, -2));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.manhattan(a), 0);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point::new(5, 5);
+        assert!(p.neighbours4().all(|n| p.manhattan(n) == 1));
+        assert_eq!(p.neighbours4().count(), 4);
+        assert!(p.neighbours8().all(|n| p.chebyshev(n) == 1));
+        assert_eq!(p.neighbours8().next(), Some(Point::new(5, 4)));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let up = Direction::Up.offset();
+        assert_eq!(up.rotate_right(), Direction::Right.offset());
+        assert_eq!(up.rotate_left(), Direction::Left.offset());
+        assert_eq!(Point::new(2, 1).rotate_right().rotate_right(), Point::new(-2, -1));
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Down.opposite(), Direction::Up);
+        assert!(Direction::ALL.iter().all(|d| d.offset().rotate_right() == d.turn_right().offset()));
+        assert_eq!(Direction8::from(Direction::Down), Direction8::S);
+    }
+}
\ No newline at end of file
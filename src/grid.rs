@@ -0,0 +1,388 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+
+use crate::{Error, Point, Result, Source, Vec2};
+
+/// Inclusive rectangle `min..=max`, empty if `max` is left of or above `min`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    const EMPTY: Bounds = Bounds { min: Point::new(0, 0), max: Point::new(-1, -1) };
+
+    pub fn contains(&self, pos: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&pos.x) && (self.min.y..=self.max.y).contains(&pos.y)
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x + 1).max(0) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y + 1).max(0) as usize
+    }
+
+    fn extend(&self, pos: Point) -> Bounds {
+        if self.width() == 0 || self.height() == 0 {
+            return Bounds { min: pos, max: pos };
+        }
+        Bounds {
+            min: Point::new(self.min.x.min(pos.x), self.min.y.min(pos.y)),
+            max: Point::new(self.max.x.max(pos.x), self.max.y.max(pos.y)),
+        }
+    }
+}
+
+/// Storage of the cells of a [`Grid`].
+pub trait Backend<T> {
+    fn get(&self, pos: Point) -> Option<&T>;
+    fn get_mut(&mut self, pos: Point) -> Option<&mut T>;
+    /// Smallest rectangle containing all cells.
+    fn bounds(&self) -> Bounds;
+    fn cells<'a>(&'a self) -> Box<dyn Iterator<Item=(Point, &'a T)> + 'a>;
+}
+
+/// Rectangular grid with a cell at every position, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dense<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Dense<T> {
+    fn index(&self, pos: Point) -> Option<usize> {
+        let (x, y) = (usize::try_from(pos.x).ok()?, usize::try_from(pos.y).ok()?);
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+}
+
+impl<T> Backend<T> for Dense<T> {
+    fn get(&self, pos: Point) -> Option<&T> {
+        self.index(pos).map(|i| &self.cells[i])
+    }
+
+    fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.index(pos).map(|i| &mut self.cells[i])
+    }
+
+    fn bounds(&self) -> Bounds {
+        Bounds { min: Point::ORIGIN, max: Point::new(self.width as i32 - 1, self.height as i32 - 1) }
+    }
+
+    fn cells<'a>(&'a self) -> Box<dyn Iterator<Item=(Point, &'a T)> + 'a> {
+        let width = self.width.max(1);
+        Box::new(self.cells.iter().enumerate().map(move |(i, cell)| (Point::new((i % width) as i32, (i / width) as i32), cell)))
+    }
+}
+
+/// Unbounded grid that only stores occupied positions, e.g. rock in an endless cave.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sparse<T> {
+    cells: HashMap<Point, T>,
+    bounds: Bounds,
+}
+
+impl<T> Backend<T> for Sparse<T> {
+    fn get(&self, pos: Point) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    fn cells<'a>(&'a self) -> Box<dyn Iterator<Item=(Point, &'a T)> + 'a> {
+        Box::new(self.cells.iter().map(|(pos, cell)| (*pos, cell)))
+    }
+}
+
+/// 2D grid over a dense or sparse [`Backend`]. Lookups outside the grid return `None`,
+/// the neighbour, row, column and ray iterators only yield existing cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T, B = Dense<T>> {
+    backend: B,
+    cell: PhantomData<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+        where T: Clone {
+        Grid::from_backend(Dense { width, height, cells: vec![value; width * height] })
+    }
+
+    /// Parses a rectangular character map, `cell` converts a character or explains why it can't.
+    pub fn parse(text: &str, mut cell: impl FnMut(char) -> std::result::Result<T, String>) -> Result<Grid<T>> {
+        let src = Source::new(text);
+        let width = text.lines().next().map_or(0, |line| line.chars().count());
+        let mut cells = vec![];
+        let mut height = 0;
+        for line in text.lines() {
+            for (i, c) in line.char_indices() {
+                cells.push(cell(c).map_err(|message| src.error(&line[i..], message))?);
+            }
+            height += 1;
+            if cells.len() != width * height {
+                return Err(src.error(line, "Grid is not rectangular"));
+            }
+        }
+        if cells.is_empty() {
+            return Err(Error::invalid("Empty grid"));
+        }
+        Ok(Grid::from_backend(Dense { width, height, cells }))
+    }
+
+    /// Row-major index of `pos`, e.g. for a bitset over all cells.
+    pub fn index_of(&self, pos: Point) -> Option<usize> {
+        self.backend.index(pos)
+    }
+
+    /// Inverse of [`Grid::index_of`].
+    pub fn point_at(&self, index: usize) -> Point {
+        Point::new((index % self.backend.width) as i32, (index / self.backend.width) as i32)
+    }
+
+    /// Replaces the cell at `pos` and returns the previous value, `None` outside the grid.
+    pub fn set(&mut self, pos: Point, value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+}
+
+impl<T> Grid<T, Sparse<T>> {
+    pub fn sparse() -> Grid<T, Sparse<T>> {
+        Grid::from_backend(Sparse { cells: HashMap::new(), bounds: Bounds::EMPTY })
+    }
+
+    /// Parses a character map, `cell` returns `None` for empty positions like `.`.
+    pub fn parse_sparse(text: &str, mut cell: impl FnMut(char) -> std::result::Result<Option<T>, String>)
+                        -> Result<Grid<T, Sparse<T>>> {
+        let src = Source::new(text);
+        let mut grid = Grid::sparse();
+        for (y, line) in text.lines().enumerate() {
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                if let Some(value) = cell(c).map_err(|message| src.error(&line[i..], message))? {
+                    grid.insert(Point::new(x as i32, y as i32), value);
+                }
+            }
+        }
+        Ok(grid)
+    }
+
+    /// Stores a cell and returns the previous one, the bounds grow to include `pos`.
+    pub fn insert(&mut self, pos: Point, value: T) -> Option<T> {
+        self.backend.bounds = self.backend.bounds.extend(pos);
+        self.backend.cells.insert(pos, value)
+    }
+
+    /// Removes a cell, the bounds are not shrunk.
+    pub fn remove(&mut self, pos: Point) -> Option<T> {
+        self.backend.cells.remove(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.backend.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.backend.cells.is_empty()
+    }
+}
+
+impl<T, B: Backend<T>> Grid<T, B> {
+    fn from_backend(backend: B) -> Grid<T, B> {
+        Grid { backend, cell: PhantomData }
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.backend.get(pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.backend.get_mut(pos)
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        self.backend.bounds()
+    }
+
+    /// Whether `pos` lies within the bounds, which does not mean a sparse grid has a cell there.
+    pub fn contains(&self, pos: Point) -> bool {
+        self.bounds().contains(pos)
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds().width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds().height()
+    }
+
+    /// All cells, row by row for dense grids and in arbitrary order for sparse ones.
+    pub fn cells(&self) -> impl Iterator<Item=(Point, &T)> + '_ {
+        self.backend.cells()
+    }
+
+    /// Position of the first cell matching `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells().find(|(_, cell)| predicate(cell)).map(|(pos, _)| pos)
+    }
+
+    fn existing<'a>(&'a self, positions: impl Iterator<Item=Point> + 'a) -> impl Iterator<Item=(Point, &'a T)> + 'a {
+        positions.filter_map(move |pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// Up, right, down and left neighbours of `pos`.
+    pub fn neighbours4(&self, pos: Point) -> impl Iterator<Item=(Point, &T)> + '_ {
+        self.existing(pos.neighbours4())
+    }
+
+    /// Orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbours8(&self, pos: Point) -> impl Iterator<Item=(Point, &T)> + '_ {
+        self.existing(pos.neighbours8())
+    }
+
+    /// Cells from `pos` (exclusive) in direction `step` up to the edge of the grid.
+    pub fn ray(&self, pos: Point, step: Vec2) -> impl Iterator<Item=(Point, &T)> + '_ {
+        let bounds = self.bounds();
+        self.existing(std::iter::successors(Some(pos + step), move |pos| Some(*pos + step))
+            .take_while(move |pos| step != Point::ORIGIN && bounds.contains(*pos)))
+    }
+
+    pub fn row(&self, y: i32) -> impl Iterator<Item=(Point, &T)> + '_ {
+        let min = self.bounds().min.x;
+        self.ray(Point::new(min - 1, y), Point::new(1, 0))
+    }
+
+    pub fn column(&self, x: i32) -> impl Iterator<Item=(Point, &T)> + '_ {
+        let min = self.bounds().min.y;
+        self.ray(Point::new(x, min - 1), Point::new(0, 1))
+    }
+
+    /// Draws the grid line by line, `cell` also gets called for empty positions of sparse grids.
+    pub fn render(&self, mut cell: impl FnMut(Point, Option<&T>) -> char) -> String {
+        let bounds = self.bounds();
+        let mut text = String::with_capacity((bounds.width() + 1) * bounds.height());
+        for y in bounds.min.y..=bounds.max.y {
+            for x in bounds.min.x..=bounds.max.x {
+                let pos = Point::new(x, y);
+                text.push(cell(pos, self.get(pos)));
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl<T, B: Backend<T>> Index<Point> for Grid<T, B> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("No cell at {:?}", pos))
+    }
+}
+
+impl<T, B: Backend<T>> IndexMut<Point> for Grid<T, B> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        self.get_mut(pos).unwrap_or_else(|| panic!("No cell at {:?}", pos))
+    }
+}
+
+/// Prints every cell with its `Display` implementation and empty positions as `.`.
+impl<T: Display, B: Backend<T>> Display for Grid<T, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let bounds = self.bounds();
+        for y in bounds.min.y..=bounds.max.y {
+            for x in bounds.min.x..=bounds.max.x {
+                match self.get(Point::new(x, y)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: i32, y: i32) -> Point {
+        Point::new(x, y)
+    }
+
+    fn digits(text: &str) -> Result<Grid<u32>> {
+        Grid::parse(text, |c| c.to_digit(10).ok_or_else(|| format!("Invalid digit '{}'", c)))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!((grid[p(0, 0)], grid[p(2, 1)]), (1, 6));
+        assert_eq!(grid.get(p(3, 0)), None);
+        assert_eq!(grid.get(p(-1, 0)), None);
+        assert_eq!(grid.find(|d| *d == 5), Some(p(1, 1)));
+        assert_eq!(grid.index_of(p(1, 1)), Some(4));
+        assert_eq!(grid.index_of(p(3, 1)), None);
+        assert_eq!(grid.point_at(5), p(2, 1));
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert!(matches!(digits("12\n4x\n"), Err(Error::Parse { line: 2, column: 2, .. })));
+        assert!(matches!(digits("12\n456\n"), Err(Error::Parse { line: 2, column: 1, .. })));
+        assert!(digits("").is_err());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789").unwrap();
+        let values = |cells: Vec<(Point, &u32)>| cells.into_iter().map(|(_, d)| *d).collect::<Vec<_>>();
+        assert_eq!(values(grid.neighbours4(p(1, 1)).collect()), vec![2, 6, 8, 4]);
+        assert_eq!(values(grid.neighbours4(p(0, 0)).collect()), vec![2, 4]);
+        assert_eq!(values(grid.neighbours8(p(1, 1)).collect()), vec![2, 3, 6, 9, 8, 7, 4, 1]);
+        assert_eq!(grid.neighbours8(p(2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_rays() {
+        let mut grid = digits("123\n456\n789").unwrap();
+        let values = |cells: Vec<(Point, &u32)>| cells.into_iter().map(|(_, d)| *d).collect::<Vec<_>>();
+        assert_eq!(values(grid.ray(p(0, 0), p(1, 1)).collect()), vec![5, 9]);
+        assert_eq!(values(grid.ray(p(1, 1), p(-1, 0)).collect()), vec![4]);
+        assert_eq!(grid.ray(p(2, 1), p(1, 0)).count(), 0);
+        assert_eq!(values(grid.row(2).collect()), vec![7, 8, 9]);
+        assert_eq!(values(grid.column(1).collect()), vec![2, 5, 8]);
+        assert_eq!(grid.set(p(1, 1), 0), Some(5));
+        assert_eq!(grid.set(p(3, 3), 0), None);
+        assert_eq!(grid.render(|_, d| if d == Some(&0) { '#' } else { '.' }), "...\n.#.\n...\n");
+    }
+
+    #[test]
+    fn test_sparse() {
+        let mut grid = Grid::parse_sparse("#..\n..#\n", |c| match c {
+            '#' => Ok(Some('#')),
+            '.' => Ok(None),
+            c => Err(format!("Invalid tile '{}'", c)),
+        }).unwrap();
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Bounds { min: p(0, 0), max: p(2, 1) });
+        grid.insert(p(-2, 3), 'o');
+        assert_eq!(grid.bounds(), Bounds { min: p(-2, 0), max: p(2, 3) });
+        assert!(grid.contains(p(-1, 2)) && grid.get(p(-1, 2)).is_none());
+        assert_eq!(grid.column(2).count(), 1);
+        assert_eq!(grid.ray(p(-2, 0), p(1, 0)).map(|(pos, _)| pos).collect::<Vec<_>>(), vec![p(0, 0)]);
+        assert_eq!(grid.to_string(), "..#..\n....#\n.....\no....\n");
+        assert_eq!(Grid::<char, Sparse<char>>::sparse().to_string(), "");
+    }
+}
\ No newline at end of file
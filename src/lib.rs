@@ -1,6 +1,32 @@
 use std::fs;
+use std::io::{self, Read};
 use std::path::Path;
 
-pub fn load_input<P: AsRef<Path>>(path: P) -> String {
-    fs::read_to_string(path).expect("Unable to read file")
-}
\ No newline at end of file
+pub mod answers;
+pub mod bench;
+pub mod days;
+mod error;
+pub mod geometry;
+pub mod grid;
+mod params;
+pub mod provider;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+mod solution;
+
+pub use error::{Error, Result, Source};
+pub use geometry::{Direction, Point, Vec2};
+pub use grid::Grid;
+pub use params::{Param, Params};
+pub use solution::{Answer, Day, load, ParsedInput, print_answer, Solution, solve};
+
+/// Reads a puzzle input, `-` reads from stdin.
+pub fn load_input<P: AsRef<Path>>(path: P) -> Result<String> {
+    if path.as_ref() == Path::new("-") {
+        let mut input = String::new();
+        return io::stdin().read_to_string(&mut input).map(|_| input)
+            .map_err(|source| Error::Io { path: path.as_ref().to_path_buf(), source });
+    }
+    fs::read_to_string(&path).map_err(|source| Error::Io { path: path.as_ref().to_path_buf(), source })
+}
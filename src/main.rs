@@ -1,3 +1,246 @@
+use std::env;
+use std::path::Path;
+use std::process;
+
+use adventofcode2022::{Day, Params};
+use adventofcode2022::answers::{Answers, ANSWERS_FILE, Verdict};
+use adventofcode2022::bench::{bench_day, print_bench};
+use adventofcode2022::provider::InputProvider;
+use adventofcode2022::runner::{Parts, print_results, select_days, solve_day};
+use adventofcode2022::scaffold::new_day;
+use serde_json::json;
+
+const USAGE: &str = "Usage:
+  aoc run <days> [--part 1|2|both] [--input <file>|-] [--param <name>=<value>]... [--confirm] [--json]
+  aoc check [<days>]
+  aoc bench <days> [--runs <n>] [--json]
+  aoc fetch <days>
+  aoc new-day <day>
+<days> is a single day (7), an inclusive range (1..15) or all.
+--param overrides a puzzle constant of a single day, e.g. --param row=10 for day 15.
+--confirm stores the answers in answers.toml, run and check compare against them.
+--json prints one JSON object per line and part (or error) instead of text.
+Missing inputs are downloaded to input/ if AOC_SESSION is set (requires the fetch feature).";
+
+struct RunArgs<'a> {
+    days: Vec<&'static Day>,
+    parts: Parts,
+    input: Option<&'a str>,
+    params: Option<Params>,
+    confirm: bool,
+    json: bool,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs<'_>, String> {
+    let mut days = None;
+    let mut parts = Parts::Both;
+    let mut input = None;
+    let mut assignments = vec![];
+    let mut confirm = false;
+    let mut json = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => parts = args.next().ok_or("Missing value for --part")?.parse()?,
+            "--input" | "-i" => input = Some(args.next().ok_or("Missing value for --input")?.as_str()),
+            "--param" | "-P" => assignments.push(args.next().ok_or("Missing value for --param")?),
+            "--confirm" => confirm = true,
+            "--json" => json = true,
+            spec if days.is_none() => days = Some(select_days(spec)?),
+            other => return Err(format!("Unexpected argument '{}'", other)),
+        }
+    }
+    let days = days.ok_or("Missing day selection")?;
+    if input.is_some() && days.len() != 1 {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    let params = match (assignments.is_empty(), days.as_slice()) {
+        (true, _) => None,
+        (false, [day]) => {
+            let mut params = day.params();
+            for assignment in assignments {
+                params.assign(assignment).map_err(|e| e.to_string())?;
+            }
+            Some(params)
+        }
+        (false, _) => return Err("--param can only be used with a single day".to_string()),
+    };
+    if (input.is_some() || params.is_some()) && confirm {
+        return Err("--confirm only applies to the real puzzle input and parameters".to_string());
+    }
+    Ok(RunArgs { days, parts, input, params, confirm, json })
+}
+
+fn parse_check_args(args: &[String]) -> Result<Vec<&'static Day>, String> {
+    match args {
+        [] => select_days("all"),
+        [spec] => select_days(spec),
+        _ => Err(format!("Unexpected argument '{}'", args[1])),
+    }
+}
+
+struct BenchArgs {
+    days: Vec<&'static Day>,
+    runs: usize,
+    json: bool,
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut days = None;
+    let mut runs = 10;
+    let mut json = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" | "-n" => {
+                let value = args.next().ok_or("Missing value for --runs")?;
+                runs = value.parse().ok().filter(|runs| *runs > 0)
+                    .ok_or(format!("Invalid number of runs '{}'", value))?;
+            }
+            "--json" => json = true,
+            spec if days.is_none() => days = Some(select_days(spec)?),
+            other => return Err(format!("Unexpected argument '{}'", other)),
+        }
+    }
+    Ok(BenchArgs { days: days.ok_or("Missing day selection")?, runs, json })
+}
+
+fn parse_fetch_args(args: &[String]) -> Result<Vec<&'static Day>, String> {
+    match args {
+        [spec] => select_days(spec),
+        [] => Err("Missing day selection".to_string()),
+        _ => Err(format!("Unexpected argument '{}'", args[1])),
+    }
+}
+
+fn parse_new_day_args(args: &[String]) -> Result<u8, String> {
+    match args {
+        [day] => day.parse().map_err(|_| format!("Invalid day '{}'", day)),
+        [] => Err("Missing day".to_string()),
+        _ => Err(format!("Unexpected argument '{}'", args[1])),
+    }
+}
+
+fn or_exit<T>(result: adventofcode2022::Result<T>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    })
+}
+
+fn input_path(provider: &InputProvider, day: &Day) -> String {
+    or_exit(provider.ensure(day.number)).display().to_string()
+}
+
+fn run(args: RunArgs) {
+    let provider = InputProvider::from_env();
+    let mut answers = or_exit(Answers::load(ANSWERS_FILE));
+    let mut regressions = 0;
+    for day in args.days {
+        let params = args.params.clone().unwrap_or_else(|| day.params());
+        // answers are only known for the real puzzle input and parameters
+        let known = if args.input.is_none() && params.is_default() { Some(&answers) } else { None };
+        let outcome = match args.input {
+            Some(path) => Ok(path.to_string()),
+            None => provider.ensure(day.number).map(|path| path.display().to_string()),
+        }.and_then(|path| solve_day(day, args.parts, &path, &params, known));
+        let run = match outcome {
+            Err(e) if args.json => {
+                println!("{}", json!({ "day": day.number, "error": e.to_string() }));
+                process::exit(1);
+            }
+            outcome => or_exit(outcome),
+        };
+        if args.json {
+            run.to_json().iter().for_each(|object| println!("{}", object));
+        } else {
+            print_results(day, &run.results);
+        }
+        regressions += run.results.iter().filter(|r| matches!(r.verdict, Verdict::Regression { .. })).count();
+        if args.confirm {
+            for result in &run.results {
+                answers.set(day.number, result.part, &result.answer);
+            }
+        }
+    }
+    if args.confirm {
+        or_exit(answers.save());
+    }
+    if regressions > 0 {
+        process::exit(1);
+    }
+}
+
+fn check(days: Vec<&'static Day>) {
+    let provider = InputProvider::from_env();
+    let answers = or_exit(Answers::load(ANSWERS_FILE));
+    let mut regressions = 0;
+    for day in days {
+        let run = or_exit(solve_day(day, Parts::Both, &input_path(&provider, day), &day.params(), Some(&answers)));
+        for result in run.results {
+            let status = match result.verdict {
+                Verdict::Unconfirmed => "unconfirmed".to_string(),
+                Verdict::Correct => "ok".to_string(),
+                Verdict::Regression { expected } => {
+                    regressions += 1;
+                    format!("REGRESSION, expected {:?} but got {:?}", expected, result.answer)
+                }
+            };
+            println!("Day {:02} part {}: {}", day.number, result.part, status);
+        }
+    }
+    if regressions > 0 {
+        eprintln!("{} regression(s)", regressions);
+        process::exit(1);
+    }
+}
+
+fn bench(args: BenchArgs) {
+    let provider = InputProvider::from_env();
+    let mut summary = vec![];
+    for day in args.days {
+        let result = or_exit(bench_day(day, &input_path(&provider, day), args.runs));
+        if args.json {
+            summary.push(result.to_json());
+        } else {
+            print_bench(&result);
+        }
+    }
+    if args.json {
+        println!("{}", serde_json::Value::Array(summary));
+    }
+}
+
+fn fetch(days: Vec<&'static Day>) {
+    let provider = InputProvider::from_env();
+    for day in days {
+        println!("Day {:02}: {}", day.number, input_path(&provider, day));
+    }
+}
+
+fn scaffold(day: u8) {
+    for path in or_exit(new_day(Path::new("."), day)) {
+        println!("Wrote {}", path.display());
+    }
+}
+
 fn main() {
-    println!("Please take a look into README.md.");
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => parse_run_args(&args[1..]).map(run),
+        Some("check") => parse_check_args(&args[1..]).map(check),
+        Some("bench") => parse_bench_args(&args[1..]).map(bench),
+        Some("fetch") => parse_fetch_args(&args[1..]).map(fetch),
+        Some("new-day") => parse_new_day_args(&args[1..]).map(scaffold),
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(command) => Err(format!("Unknown command '{}'", command)),
+        None => Err("Missing command".to_string()),
+    };
+    if let Err(e) = result {
+        eprintln!("{}\n{}", e, USAGE);
+        process::exit(2);
+    }
 }
@@ -0,0 +1,96 @@
+use crate::{Error, Result};
+
+/// Puzzle constant of a day that can be overridden on the command line, e.g. day 15's row.
+#[derive(Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+    /// Range of accepted values (inclusive).
+    pub min: i64,
+    pub max: i64,
+}
+
+/// Values of the parameters of one day, starting out with the defaults.
+#[derive(Debug, Clone)]
+pub struct Params {
+    spec: &'static [Param],
+    values: Vec<i64>,
+}
+
+impl Params {
+    pub fn new(spec: &'static [Param]) -> Params {
+        Params { spec, values: spec.iter().map(|param| param.default).collect() }
+    }
+
+    pub fn spec(&self) -> &'static [Param] {
+        self.spec
+    }
+
+    /// Panics on names the day does not declare, which is a bug in the day.
+    pub fn get(&self, name: &str) -> i64 {
+        let index = self.spec.iter().position(|param| param.name == name)
+            .unwrap_or_else(|| panic!("Undeclared parameter '{}'", name));
+        self.values[index]
+    }
+
+    pub fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        let index = self.spec.iter().position(|param| param.name == name).ok_or_else(|| {
+            match self.spec {
+                [] => Error::invalid(format!("Unknown parameter '{}', this day has no parameters", name)),
+                spec => Error::invalid(format!("Unknown parameter '{}', expected one of: {}", name,
+                                               spec.iter().map(|param| param.name).collect::<Vec<_>>().join(", "))),
+            }
+        })?;
+        let (min, max) = (self.spec[index].min, self.spec[index].max);
+        self.values[index] = value.parse().ok()
+            .filter(|value| (min..=max).contains(value))
+            .ok_or_else(|| Error::invalid(format!("Invalid value '{}' for {}, expected an integer from {} to {}",
+                                                  value, name, min, max)))?;
+        Ok(())
+    }
+
+    /// Parses a `name=value` assignment.
+    pub fn assign(&mut self, assignment: &str) -> Result<()> {
+        let (name, value) = assignment.split_once('=')
+            .ok_or_else(|| Error::invalid(format!("Expected name=value instead of '{}'", assignment)))?;
+        self.set(name, value)
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.spec.iter().zip(&self.values).all(|(param, value)| param.default == *value)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &[Param] = &[
+        Param { name: "row", default: 2000000, min: -100, max: i64::MAX },
+        Param { name: "limit", default: 4000000, min: 0, max: 4000000 },
+    ];
+
+    #[test]
+    fn test_params() {
+        let mut params = Params::new(SPEC);
+        assert!(params.is_default());
+        assert_eq!(params.get("row"), 2000000);
+        params.set("row", "-10").unwrap();
+        params.assign("limit=20").unwrap();
+        assert_eq!((params.get("row"), params.get("limit")), (-10, 20));
+        assert!(!params.is_default());
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        let mut params = Params::new(SPEC);
+        assert_eq!(params.set("rows", "1").unwrap_err().to_string(),
+                   "Unknown parameter 'rows', expected one of: row, limit");
+        assert!(params.set("limit", "-1").is_err());
+        assert!(params.set("limit", "4000001").is_err());
+        assert!(params.set("limit", "ten").is_err());
+        assert!(params.assign("limit").is_err());
+        assert!(Params::new(&[]).set("row", "1").is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,240 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use crate::{Error, Result};
+
+/// Directory holding the real puzzle inputs, which doubles as the download cache.
+pub const INPUT_DIR: &str = "input";
+
+/// Environment variable with the adventofcode.com session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Supplies the puzzle input of a day that is not cached yet.
+pub trait Fetcher {
+    fn fetch(&self, day: u8) -> Result<String>;
+}
+
+impl<F: Fn(u8) -> Result<String>> Fetcher for F {
+    fn fetch(&self, day: u8) -> Result<String> {
+        self(day)
+    }
+}
+
+/// Copies inputs from another directory laid out like `input/`, e.g. a shared mirror.
+pub struct DirectoryFetcher {
+    dir: PathBuf,
+}
+
+impl DirectoryFetcher {
+    pub fn new(dir: impl AsRef<Path>) -> DirectoryFetcher {
+        DirectoryFetcher { dir: dir.as_ref().to_path_buf() }
+    }
+}
+
+impl Fetcher for DirectoryFetcher {
+    fn fetch(&self, day: u8) -> Result<String> {
+        crate::load_input(self.dir.join(file_name(day)))
+    }
+}
+
+/// Downloads inputs from adventofcode.com with the session cookie of a logged in user.
+#[cfg(feature = "fetch")]
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+}
+
+#[cfg(feature = "fetch")]
+impl HttpFetcher {
+    pub fn new(session: impl Into<String>) -> HttpFetcher {
+        HttpFetcher { base_url: "https://adventofcode.com/2022".to_string(), session: session.into() }
+    }
+
+    /// Reads the session cookie from `AOC_SESSION`.
+    pub fn from_env() -> Option<HttpFetcher> {
+        std::env::var(SESSION_VAR).ok()
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty())
+            .map(HttpFetcher::new)
+    }
+
+    /// Points the fetcher to another server, e.g. a local mock.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> HttpFetcher {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+#[cfg(feature = "fetch")]
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, day: u8) -> Result<String> {
+        let url = format!("{}/day/{}/input", self.base_url.trim_end_matches('/'), day);
+        let fail = |message: String| Error::Fetch { day, message };
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", concat!(env!("CARGO_PKG_NAME"), " input cache by ", env!("CARGO_PKG_AUTHORS")))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(status, _) => fail(format!("{} answered with status {}", url, status)),
+                e => fail(e.to_string()),
+            })?;
+        response.into_string().map_err(|e| fail(e.to_string()))
+    }
+}
+
+fn file_name(day: u8) -> String {
+    format!("{:02}.txt", day)
+}
+
+/// Loads puzzle inputs from a cache directory, downloading missing ones with a [`Fetcher`].
+pub struct InputProvider {
+    cache_dir: PathBuf,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl InputProvider {
+    /// Provider that only reads the cache, missing inputs are an error.
+    pub fn new(cache_dir: impl AsRef<Path>) -> InputProvider {
+        InputProvider { cache_dir: cache_dir.as_ref().to_path_buf(), fetcher: None }
+    }
+
+    /// Provider for `input/` that downloads missing inputs if a session cookie is configured.
+    pub fn from_env() -> InputProvider {
+        let provider = InputProvider::new(INPUT_DIR);
+        #[cfg(feature = "fetch")]
+        if let Some(fetcher) = HttpFetcher::from_env() {
+            return provider.with_fetcher(fetcher);
+        }
+        provider
+    }
+
+    pub fn with_fetcher(mut self, fetcher: impl Fetcher + 'static) -> InputProvider {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(file_name(day))
+    }
+
+    /// Returns the path of the cached input, fetching it first if needed.
+    pub fn ensure(&self, day: u8) -> Result<PathBuf> {
+        let path = self.cache_path(day);
+        match fs::metadata(&path) {
+            Ok(_) => return Ok(path),
+            Err(e) if e.kind() == ErrorKind::NotFound && self.fetcher.is_some() => {}
+            Err(source) => return Err(Error::Io { path, source }),
+        }
+        let text = self.fetcher.as_ref().unwrap().fetch(day)?;
+        store(&path, &text)?;
+        Ok(path)
+    }
+
+    pub fn load(&self, day: u8) -> Result<String> {
+        crate::load_input(self.ensure(day)?)
+    }
+}
+
+// writes to a temporary file next to `path` and renames it, so readers never see partial inputs
+fn store(path: &Path, text: &str) -> Result<()> {
+    let io_error = |source| Error::Io { path: path.to_path_buf(), source };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let temporary = path.with_extension(format!("tmp{}", std::process::id()));
+    fs::write(&temporary, text)
+        .and_then(|_| fs::rename(&temporary, path))
+        .map_err(|source| {
+            let _ = fs::remove_file(&temporary);
+            io_error(source)
+        })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::env;
+    use std::rc::Rc;
+
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-provider-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_cache_only() {
+        let provider = InputProvider::new(INPUT_DIR);
+        assert_eq!(provider.cache_path(7), Path::new("input/07.txt"));
+        assert_eq!(provider.load(1).unwrap(), crate::load_input("input/01.txt").unwrap());
+        assert!(matches!(provider.load(25), Err(Error::Io { .. })));
+    }
+
+    #[test]
+    fn test_fetch_once() {
+        let dir = temp_dir("cache");
+        let calls = Rc::new(Cell::new(0));
+        let counter = calls.clone();
+        let provider = InputProvider::new(&dir).with_fetcher(move |day| {
+            counter.set(counter.get() + 1);
+            Ok(format!("input of day {}\n", day))
+        });
+        assert_eq!(provider.load(3).unwrap(), "input of day 3\n");
+        assert_eq!(provider.load(3).unwrap(), "input of day 3\n");
+        assert_eq!(calls.get(), 1);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_error() {
+        let dir = temp_dir("error");
+        let provider = InputProvider::new(&dir)
+            .with_fetcher(|day| Err(Error::Fetch { day, message: "offline".to_string() }));
+        let error = provider.load(4).unwrap_err();
+        assert_eq!(error.to_string(), "Unable to fetch the input of day 4: offline");
+        assert!(!provider.cache_path(4).exists());
+    }
+
+    #[test]
+    fn test_directory_fetcher() {
+        let dir = temp_dir("mirror");
+        let provider = InputProvider::new(&dir).with_fetcher(DirectoryFetcher::new(INPUT_DIR));
+        assert_eq!(provider.load(2).unwrap(), crate::load_input("input/02.txt").unwrap());
+        assert!(provider.load(25).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn test_http_fetcher() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            for line in BufReader::new(&stream).lines() {
+                let line = line.unwrap();
+                if line.is_empty() {
+                    break;
+                }
+                request.push(line);
+            }
+            let body = "1000\n2000\n";
+            write!(&stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body).unwrap();
+            request
+        });
+        let fetcher = HttpFetcher::new("secret").with_base_url(format!("http://{}/2022", address));
+        assert_eq!(fetcher.fetch(1).unwrap(), "1000\n2000\n");
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2022/day/1/input HTTP/1.1");
+        assert!(request.iter().any(|line| line.eq_ignore_ascii_case("cookie: session=secret")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,190 @@
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::{Day, days, load_input, Params, print_answer};
+use crate::answers::{Answers, Verdict};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parts {
+    One,
+    Two,
+    Both,
+}
+
+impl Parts {
+    pub fn includes(&self, part: u8) -> bool {
+        matches!((self, part), (Parts::Both, _) | (Parts::One, 1) | (Parts::Two, 2))
+    }
+}
+
+impl FromStr for Parts {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Parts::One),
+            "2" => Ok(Parts::Two),
+            "both" => Ok(Parts::Both),
+            _ => Err(format!("Invalid part '{}', expected 1, 2 or both", s)),
+        }
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    s.parse().map_err(|_| format!("Invalid day '{}'", s))
+}
+
+// accepts `7`, `1..15` (both ends inclusive), `1..=15`, `all` and comma separated lists of those
+pub fn select_days(spec: &str) -> Result<Vec<&'static Day>, String> {
+    if spec == "all" {
+        return Ok(days::DAYS.iter().collect());
+    }
+    let mut selected: Vec<&'static Day> = vec![];
+    for part in spec.split(',') {
+        let (from, to) = match part.split_once("..") {
+            Some((from, to)) => (parse_day(from)?, parse_day(to.trim_start_matches('='))?),
+            None => (parse_day(part)?, parse_day(part)?),
+        };
+        if from > to {
+            return Err(format!("Empty day range '{}'", part));
+        }
+        for number in from..=to {
+            let day = days::get(number).ok_or(format!("Day {} is not solved yet", number))?;
+            if !selected.iter().any(|d| d.number == number) {
+                selected.push(day);
+            }
+        }
+    }
+    Ok(selected)
+}
+
+pub struct PartResult {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub duration: Duration,
+}
+
+/// Results of one day together with what identifies the run: the input and the parse time.
+pub struct DayRun {
+    pub day: u8,
+    pub input_sha256: String,
+    pub parse: Duration,
+    pub results: Vec<PartResult>,
+}
+
+impl DayRun {
+    /// One JSON object per part, e.g. for `aoc run --json`.
+    pub fn to_json(&self) -> Vec<Value> {
+        self.results.iter().map(|result| {
+            let mut object = json!({
+                "day": self.day,
+                "part": result.part,
+                "answer": result.answer,
+                "duration_ns": result.duration.as_nanos() as u64,
+                "parse_ns": self.parse.as_nanos() as u64,
+                "input_sha256": self.input_sha256,
+            });
+            object["status"] = match &result.verdict {
+                Verdict::Unconfirmed => json!("unconfirmed"),
+                Verdict::Correct => json!("ok"),
+                Verdict::Regression { expected } => {
+                    object["expected"] = json!(expected);
+                    json!("regression")
+                }
+            };
+            object
+        }).collect()
+    }
+}
+
+pub fn sha256(text: &str) -> String {
+    Sha256::digest(text.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Solves the requested parts of a day on the given input file.
+/// Answers are only checked against `answers` if given, i.e. for the day's real input.
+pub fn solve_day(day: &Day, parts: Parts, path: &str, params: &Params, answers: Option<&Answers>)
+                 -> crate::Result<DayRun> {
+    let input = load_input(path)?;
+    let input_sha256 = sha256(&input);
+    let start = Instant::now();
+    let parsed = day.parse(input).map_err(|e| e.in_file(path))?;
+    let parse = start.elapsed();
+    let mut results = vec![];
+    for part in [1, 2].into_iter().filter(|part| parts.includes(*part)) {
+        let start = Instant::now();
+        let answer = if part == 1 { day.part1_with(&parsed, params) } else { day.part2_with(&parsed, params) };
+        let duration = start.elapsed();
+        let answer = answer.map_err(|e| e.in_file(path))?;
+        let verdict = answers.map_or(Verdict::Unconfirmed, |a| a.check(day.number, part, &answer));
+        results.push(PartResult { part, answer, verdict, duration });
+    }
+    Ok(DayRun { day: day.number, input_sha256, parse, results })
+}
+
+pub fn print_results(day: &Day, results: &[PartResult]) {
+    println!("Day {:02}", day.number);
+    for result in results {
+        let label = if result.part == 1 { "First task" } else { "Second task" };
+        match &result.verdict {
+            Verdict::Unconfirmed => print_answer(label, &result.answer),
+            Verdict::Correct => print_answer(&format!("{} (verified)", label), &result.answer),
+            Verdict::Regression { expected } => {
+                print_answer(&format!("{} (REGRESSION)", label), &result.answer);
+                print_answer("    expected", expected);
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(spec: &str) -> Vec<u8> {
+        select_days(spec).unwrap().iter().map(|d| d.number).collect()
+    }
+
+    #[test]
+    fn test_select_days() {
+        assert_eq!(numbers("7"), vec![7]);
+        assert_eq!(numbers("1..3"), vec![1, 2, 3]);
+        assert_eq!(numbers("1..=3,2,9"), vec![1, 2, 3, 9]);
+        assert_eq!(numbers("all").len(), days::DAYS.len());
+    }
+
+    #[test]
+    fn test_select_days_invalid() {
+        assert!(select_days("3..1").is_err());
+        assert!(select_days("x").is_err());
+        assert!(select_days("25").is_err());
+    }
+
+    #[test]
+    fn test_parts() {
+        assert_eq!("both".parse::<Parts>(), Ok(Parts::Both));
+        assert!(Parts::One.includes(1) && !Parts::One.includes(2));
+        assert!("3".parse::<Parts>().is_err());
+    }
+
+    #[test]
+    fn test_solve_day_json() {
+        let day = days::get(5).unwrap();
+        let run = solve_day(day, Parts::Both, "input/05.test.txt", &day.params(), None).unwrap();
+        assert_eq!(run.input_sha256, sha256(&load_input("input/05.test.txt").unwrap()));
+        let json = run.to_json();
+        assert_eq!(json.len(), 2);
+        assert_eq!((&json[0]["day"], &json[0]["part"], &json[0]["answer"]), (&json!(5), &json!(1), &json!("CMZ")));
+        assert_eq!(json[1]["status"], "unconfirmed");
+        assert!(json[1]["duration_ns"].is_u64());
+    }
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(sha256("abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+}
@@ -0,0 +1,140 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{Error, Result};
+
+const DAY_TEMPLATE: &str = include_str!("days/dayxx_template.rs");
+const BIN_TEMPLATE: &str = include_str!("bin/dayxx_template.rs");
+const REGISTRY: &str = "src/days/mod.rs";
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("dayxx_template", &format!("day{:02}", day))
+        .replace("DayXX", &format!("Day{:02}", day))
+        .replace("input/xx.", &format!("input/{:02}.", day))
+}
+
+// inserts `line` among the lines starting with `prefix`, keeping them sorted
+fn insert_sorted(lines: &mut Vec<String>, prefix: &str, line: String) -> Result<()> {
+    let matching: Vec<usize> = lines.iter()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with(prefix) && !l.contains("template"))
+        .map(|(i, _)| i)
+        .collect();
+    let last = *matching.last()
+        .ok_or_else(|| Error::invalid(format!("No '{}' entries found in {}", prefix.trim(), REGISTRY)))?;
+    if matching.iter().any(|&i| lines[i] == line) {
+        return Err(Error::invalid(format!("'{}' is already registered", line.trim())));
+    }
+    let position = matching.iter()
+        .find(|&&i| lines[i] > line)
+        .copied()
+        .unwrap_or(last + 1);
+    lines.insert(position, line);
+    Ok(())
+}
+
+/// Adds the module declaration and the `DAYS` entry of a new day to the registry source.
+pub fn register(registry: &str, day: u8) -> Result<String> {
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+    insert_sorted(&mut lines, "pub mod day", format!("pub mod day{:02};", day))?;
+    insert_sorted(&mut lines, "    Day::new::<day", format!("    Day::new::<day{0:02}::Day{0:02}>({1}),", day, day))?;
+    let mut registry_new = lines.join("\n");
+    if registry.ends_with('\n') {
+        registry_new.push('\n');
+    }
+    Ok(registry_new)
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).map_err(|source| Error::Io { path: path.to_path_buf(), source })
+}
+
+/// Generates a new day from `dayxx_template.rs` below the repository `root`:
+/// the day module, its binary, the registry entry and empty input files.
+/// Returns the paths of all created or modified files.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::invalid(format!("There is no day {} in Advent of Code", day)));
+    }
+    let registry_path = root.join(REGISTRY);
+    let registry = fs::read_to_string(&registry_path)
+        .map_err(|source| Error::Io { path: registry_path.clone(), source })?;
+    let registry = register(&registry, day)?;
+
+    let files = [
+        (root.join(format!("src/days/day{:02}.rs", day)), render(DAY_TEMPLATE, day)),
+        (root.join(format!("src/bin/day{:02}.rs", day)), render(BIN_TEMPLATE, day)),
+        (root.join(format!("input/{:02}.txt", day)), String::new()),
+        (root.join(format!("input/{:02}.test.txt", day)), String::new()),
+    ];
+    if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+        return Err(Error::invalid(format!("{} already exists", path.display())));
+    }
+    for (path, contents) in &files {
+        write(path, contents)?;
+    }
+    write(&registry_path, &registry)?;
+
+    let mut changed: Vec<PathBuf> = files.into_iter().map(|(path, _)| path).collect();
+    changed.push(registry_path);
+    Ok(changed)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const REGISTRY_SOURCE: &str = "use crate::Day;
+
+pub mod day01;
+pub mod day03;
+pub mod dayxx_template;
+
+pub static DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1),
+    Day::new::<day03::Day03>(3),
+];
+";
+
+    #[test]
+    fn test_register() {
+        let registry = register(REGISTRY_SOURCE, 2).unwrap();
+        assert!(registry.contains("pub mod day01;\npub mod day02;\npub mod day03;\npub mod dayxx_template;"));
+        assert!(registry.contains("(1),\n    Day::new::<day02::Day02>(2),\n    Day::new::<day03::Day03>(3),\n];\n"));
+        let registry = register(REGISTRY_SOURCE, 16).unwrap();
+        assert!(registry.contains("pub mod day03;\npub mod day16;\npub mod dayxx_template;"));
+        assert!(registry.contains("(3),\n    Day::new::<day16::Day16>(16),\n];"));
+        assert!(register(REGISTRY_SOURCE, 3).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let source = render(DAY_TEMPLATE, 16);
+        assert!(source.contains("pub struct Day16;"));
+        assert!(source.contains("load_input(\"input/16.test.txt\")"));
+        let binary = render(BIN_TEMPLATE, 16);
+        assert!(binary.contains("use adventofcode2022::days::day16::Day16;"));
+        assert!(binary.contains("solve::<Day16>(\"input/16.txt\");"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::create_dir_all(root.join("src/bin")).unwrap();
+        fs::create_dir_all(root.join("input")).unwrap();
+        fs::write(root.join(REGISTRY), REGISTRY_SOURCE).unwrap();
+
+        let changed = new_day(&root, 16).unwrap();
+        assert_eq!(changed.len(), 5);
+        assert!(changed.iter().all(|path| path.exists()));
+        assert!(fs::read_to_string(root.join(REGISTRY)).unwrap().contains("pub mod day16;"));
+        assert!(new_day(&root, 16).is_err());
+        assert!(new_day(&root, 26).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,255 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Shortest route to a goal: its total cost and the nodes from the start to the goal (both included).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<N, C> {
+    pub cost: C,
+    pub path: Vec<N>,
+}
+
+// every discovered node gets an index, parents point to the index of their predecessor
+struct Visited<N> {
+    nodes: Vec<N>,
+    parents: Vec<usize>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Visited<N> {
+    fn new(start: N) -> Visited<N> {
+        Visited { nodes: vec![start.clone()], parents: vec![0], index: HashMap::from([(start, 0)]) }
+    }
+
+    fn path(&self, mut i: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[i].clone()];
+        while i != 0 {
+            i = self.parents[i];
+            path.push(self.nodes[i].clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth-first search for the closest node matching `goal`, every step costs 1.
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I, mut goal: impl FnMut(&N) -> bool)
+                 -> Option<Found<N, usize>>
+    where N: Clone + Eq + Hash, I: IntoIterator<Item=N> {
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+    while let Some((i, steps)) = queue.pop_front() {
+        if goal(&visited.nodes[i]) {
+            return Some(Found { cost: steps, path: visited.path(i) });
+        }
+        for neighbour in neighbours(&visited.nodes[i]) {
+            if let Entry::Vacant(entry) = visited.index.entry(neighbour.clone()) {
+                entry.insert(visited.nodes.len());
+                queue.push_back((visited.nodes.len(), steps + 1));
+                visited.nodes.push(neighbour);
+                visited.parents.push(i);
+            }
+        }
+    }
+    None
+}
+
+/// Fixed size set of small integers, one bit each.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new(size: usize) -> BitSet {
+        BitSet { words: vec![0; size.div_ceil(64)] }
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        self.words[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    /// Adds `i` and returns whether it was missing before.
+    pub fn insert(&mut self, i: usize) -> bool {
+        let missing = !self.contains(i);
+        self.words[i / 64] |= 1 << (i % 64);
+        missing
+    }
+}
+
+/// Breadth-first search over nodes numbered `0..size`, e.g. the cells of a dense grid.
+/// Avoids hashing entirely, which makes it suitable for maps with millions of cells.
+pub fn bfs_indexed<I>(size: usize, start: usize, mut neighbours: impl FnMut(usize) -> I,
+                      mut goal: impl FnMut(usize) -> bool) -> Option<Found<usize, usize>>
+    where I: IntoIterator<Item=usize> {
+    let mut visited = BitSet::new(size);
+    let mut parents = vec![usize::MAX; size];
+    let mut queue = VecDeque::from([(start, 0)]);
+    visited.insert(start);
+    while let Some((node, steps)) = queue.pop_front() {
+        if goal(node) {
+            let mut path = vec![node];
+            while path[path.len() - 1] != start {
+                path.push(parents[path[path.len() - 1]]);
+            }
+            path.reverse();
+            return Some(Found { cost: steps, path });
+        }
+        for neighbour in neighbours(node) {
+            if visited.insert(neighbour) {
+                parents[neighbour] = node;
+                queue.push_back((neighbour, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Steps from `start` to every node numbered `0..size`, `None` for unreachable nodes.
+pub fn distances_indexed<I>(size: usize, start: usize, mut neighbours: impl FnMut(usize) -> I) -> Vec<Option<u32>>
+    where I: IntoIterator<Item=usize> {
+    let mut distances = vec![None; size];
+    let mut queue = VecDeque::from([start]);
+    distances[start] = Some(0);
+    while let Some(node) = queue.pop_front() {
+        let steps = distances[node].unwrap() + 1;
+        for neighbour in neighbours(node) {
+            if distances[neighbour].is_none() {
+                distances[neighbour] = Some(steps);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    distances
+}
+
+/// Dijkstra's algorithm for the cheapest node matching `goal`, `neighbours` yields nodes with step costs.
+pub fn dijkstra<N, C, I>(start: N, neighbours: impl FnMut(&N) -> I, goal: impl FnMut(&N) -> bool)
+                         -> Option<Found<N, C>>
+    where N: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output=C>, I: IntoIterator<Item=(N, C)> {
+    astar(start, neighbours, |_| C::default(), goal)
+}
+
+// heap entry ordered by the estimated total cost, ties prefer the node closer to the goal
+#[derive(PartialEq, Eq)]
+struct State<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for State<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.estimate.cmp(&other.estimate).then_with(|| other.cost.cmp(&self.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for State<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A* search for the cheapest node matching `goal`. `heuristic` estimates the remaining cost
+/// and must never overestimate it, otherwise the result may not be the cheapest one.
+pub fn astar<N, C, I>(start: N, mut neighbours: impl FnMut(&N) -> I, mut heuristic: impl FnMut(&N) -> C,
+                      mut goal: impl FnMut(&N) -> bool) -> Option<Found<N, C>>
+    where N: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output=C>, I: IntoIterator<Item=(N, C)> {
+    let mut visited = Visited::new(start);
+    let mut costs = vec![C::default()];
+    let mut heap = BinaryHeap::from([Reverse(State { estimate: heuristic(&visited.nodes[0]), cost: C::default(), index: 0 })]);
+    while let Some(Reverse(State { cost, index, .. })) = heap.pop() {
+        if cost > costs[index] {
+            continue; // outdated entry, the node was reached cheaper in the meantime
+        }
+        if goal(&visited.nodes[index]) {
+            return Some(Found { cost, path: visited.path(index) });
+        }
+        for (neighbour, step) in neighbours(&visited.nodes[index]) {
+            let total = cost + step;
+            let next = match visited.index.entry(neighbour.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(visited.nodes.len());
+                    visited.nodes.push(neighbour);
+                    visited.parents.push(index);
+                    costs.push(total);
+                    visited.nodes.len() - 1
+                }
+                Entry::Occupied(entry) if total < costs[*entry.get()] => {
+                    let next = *entry.get();
+                    visited.parents[next] = index;
+                    costs[next] = total;
+                    next
+                }
+                Entry::Occupied(_) => continue,
+            };
+            heap.push(Reverse(State { estimate: total + heuristic(&visited.nodes[next]), cost: total, index: next }));
+        }
+    }
+    None
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{Grid, Point};
+
+    use super::*;
+
+    // weighted directed graph: a -1-> b -1-> c -1-> d, a -5-> d, b -1-> e
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('d', 5), ('b', 1)],
+            'b' => vec![('c', 1), ('e', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let unweighted = |node: &char| edges(node).into_iter().map(|(n, _)| n);
+        assert_eq!(bfs('a', unweighted, |n| *n == 'd'), Some(Found { cost: 1, path: vec!['a', 'd'] }));
+        assert_eq!(bfs('a', unweighted, |n| *n == 'e').unwrap().path, vec!['a', 'b', 'e']);
+        assert_eq!(bfs('a', unweighted, |n| *n == 'a').unwrap().cost, 0);
+        assert_eq!(bfs('c', unweighted, |n| *n == 'a'), None);
+    }
+
+    #[test]
+    fn test_bfs_indexed() {
+        // ring 0 - 1 - ... - 9 - 0
+        let ring = |i: usize| [(i + 1) % 10, (i + 9) % 10];
+        assert_eq!(bfs_indexed(10, 2, ring, |i| i == 8), Some(Found { cost: 4, path: vec![2, 1, 0, 9, 8] }));
+        assert_eq!(bfs_indexed(10, 2, ring, |i| i == 2).unwrap().path, vec![2]);
+        assert_eq!(bfs_indexed(10, 2, |i| [i], |i| i == 3), None);
+        assert_eq!(distances_indexed(10, 2, ring)[7], Some(5));
+        assert_eq!(distances_indexed(3, 0, |i| [(i + 1).min(1)]), vec![Some(0), Some(1), None]);
+        let mut set = BitSet::new(130);
+        assert!(set.insert(129) && !set.insert(129));
+        assert!(set.contains(129) && !set.contains(128));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(dijkstra('a', edges, |n| *n == 'd'), Some(Found { cost: 3, path: vec!['a', 'b', 'c', 'd'] }));
+        assert_eq!(dijkstra('a', edges, |n| matches!(n, 'c' | 'e')).unwrap().cost, 2);
+        assert_eq!(dijkstra('d', edges, |n| *n == 'a'), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let maze = Grid::parse("..#....\n..#.##.\n....#..\n", |c| Ok(c == '#')).unwrap();
+        let (start, end) = (Point::new(0, 0), Point::new(6, 0));
+        let neighbours = |p: &Point| maze.neighbours4(*p)
+            .filter(|(_, wall)| !**wall)
+            .map(|(n, _)| (n, 1))
+            .collect::<Vec<_>>();
+        let found = astar(start, neighbours, |p| p.manhattan(end), |p| *p == end).unwrap();
+        assert_eq!(found.cost, 10);
+        assert_eq!(found.path.len(), 11);
+        assert!(found.path.windows(2).all(|step| step[0].manhattan(step[1]) == 1));
+        assert_eq!(dijkstra(start, neighbours, |p| *p == end).unwrap().cost, 10);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,197 @@
+use std::any::Any;
+use std::env;
+use std::path::Path;
+use std::process;
+
+use crate::{Error, load_input, Param, Params, Result};
+
+/// What a part returns: a printable answer, or a `Result` of one for inputs that may have no answer.
+pub trait Answer {
+    fn into_answer(self) -> Result<String>;
+}
+
+macro_rules! display_answers {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn into_answer(self) -> Result<String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+display_answers!(i32, i64, u32, u64, usize, String);
+
+impl<T: Answer> Answer for Result<T> {
+    fn into_answer(self) -> Result<String> {
+        self?.into_answer()
+    }
+}
+
+/// Common shape of every day: parse the puzzle input once, then solve both parts on it.
+pub trait Solution {
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: String) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    /// Puzzle constants that can be overridden on the command line.
+    const PARAMS: &'static [Param] = &[];
+
+    /// Days declaring `PARAMS` override these to solve with the given values.
+    fn part1_with(input: &Self::Input, _params: &Params) -> Self::Answer1 {
+        Self::part1(input)
+    }
+
+    fn part2_with(input: &Self::Input, _params: &Params) -> Self::Answer2 {
+        Self::part2(input)
+    }
+}
+
+/// Parsed input of a day whose concrete type has been erased by the registry.
+pub struct ParsedInput(Box<dyn Any>);
+
+/// Type erased entry of the day registry, see `days::DAYS`.
+pub struct Day {
+    pub number: u8,
+    params: &'static [Param],
+    parse: fn(String) -> Result<ParsedInput>,
+    part1: fn(&ParsedInput, &Params) -> Result<String>,
+    part2: fn(&ParsedInput, &Params) -> Result<String>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(number: u8) -> Day
+        where S::Input: 'static {
+        Day {
+            number,
+            params: S::PARAMS,
+            parse: parse_erased::<S>,
+            part1: part1_erased::<S>,
+            part2: part2_erased::<S>,
+        }
+    }
+
+    /// Default location of the puzzle input, e.g. `input/07.txt`.
+    pub fn input_path(&self) -> String {
+        format!("{}/{:02}.txt", crate::provider::INPUT_DIR, self.number)
+    }
+
+    /// Default values of the parameters of this day.
+    pub fn params(&self) -> Params {
+        Params::new(self.params)
+    }
+
+    pub fn parse(&self, input: String) -> Result<ParsedInput> {
+        (self.parse)(input)
+    }
+
+    /// Reads and parses the input file, errors point into that file.
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<ParsedInput> {
+        self.parse(load_input(&path)?).map_err(|e| e.in_file(path))
+    }
+
+    pub fn part1(&self, input: &ParsedInput) -> Result<String> {
+        self.part1_with(input, &self.params())
+    }
+
+    pub fn part2(&self, input: &ParsedInput) -> Result<String> {
+        self.part2_with(input, &self.params())
+    }
+
+    pub fn part1_with(&self, input: &ParsedInput, params: &Params) -> Result<String> {
+        (self.part1)(input, params)
+    }
+
+    pub fn part2_with(&self, input: &ParsedInput, params: &Params) -> Result<String> {
+        (self.part2)(input, params)
+    }
+}
+
+fn parse_erased<S: Solution>(input: String) -> Result<ParsedInput>
+    where S::Input: 'static {
+    Ok(ParsedInput(Box::new(S::parse(input)?)))
+}
+
+fn part1_erased<S: Solution>(input: &ParsedInput, params: &Params) -> Result<String>
+    where S::Input: 'static {
+    S::part1_with(downcast::<S>(input), params).into_answer()
+}
+
+fn part2_erased<S: Solution>(input: &ParsedInput, params: &Params) -> Result<String>
+    where S::Input: 'static {
+    S::part2_with(downcast::<S>(input), params).into_answer()
+}
+
+fn downcast<S: Solution>(input: &ParsedInput) -> &S::Input
+    where S::Input: 'static {
+    input.0.downcast_ref().expect("Parsed input belongs to a different day")
+}
+
+/// Prints an answer, multiline answers (e.g. day 10's CRT) start on a new line.
+pub fn print_answer(label: &str, answer: &str) {
+    if answer.contains('\n') {
+        println!("{}: \n{}", label, answer);
+    } else {
+        println!("{}: {}", label, answer);
+    }
+}
+
+/// Reads and parses the input file of a day, errors point into that file.
+pub fn load<S: Solution>(path: impl AsRef<Path>) -> Result<S::Input> {
+    S::parse(load_input(&path)?).map_err(|e| e.in_file(path))
+}
+
+fn usage<S: Solution>(default_path: &Path) -> String {
+    let mut usage = format!("Usage: {} [<input file>|-] [--<param> <value>]...\n<input file> defaults to {}, - reads stdin.",
+                            env::args().next().unwrap_or_default(), default_path.display());
+    for param in S::PARAMS {
+        usage.push_str(&format!("\n  --{:<8} default {}", param.name, param.default));
+    }
+    usage
+}
+
+// reads `[<input file>|-] [--<param> <value>]...`
+fn parse_args<S: Solution>(args: &[String], default_path: &Path) -> Result<(String, Params)> {
+    let mut path = None;
+    let mut params = Params::new(S::PARAMS);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.strip_prefix("--") {
+            Some(name) => {
+                let value = args.next().ok_or_else(|| Error::invalid(format!("Missing value for --{}", name)))?;
+                params.set(name, value)?;
+            }
+            None if path.is_none() => path = Some(arg.clone()),
+            None => return Err(Error::invalid(format!("Unexpected argument '{}'", arg))),
+        }
+    }
+    Ok((path.unwrap_or_else(|| default_path.display().to_string()), params))
+}
+
+/// Entry point shared by the `src/bin/dayNN.rs` binaries: solves the input file given on the
+/// command line (`default_path` otherwise) with the parameters declared by the day.
+pub fn solve<S: Solution>(default_path: impl AsRef<Path>) {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{}", usage::<S>(default_path.as_ref()));
+        return;
+    }
+    let (path, params) = parse_args::<S>(&args, default_path.as_ref()).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, usage::<S>(default_path.as_ref()));
+        process::exit(2);
+    });
+    let input = load::<S>(&path).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    let answer = |answer: Result<String>| answer.unwrap_or_else(|e| {
+        eprintln!("{}", e.in_file(&path));
+        process::exit(1);
+    });
+    print_answer("First task", &answer(S::part1_with(&input, &params).into_answer()));
+    print_answer("Second task", &answer(S::part2_with(&input, &params).into_answer()));
+}